@@ -1,14 +1,63 @@
 use std::iter::Extend;
 use std::pin::Pin;
-use std::process::Child;
+use std::process::{Child, ExitStatus};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{collections::HashMap, future::Future};
 
-use signal_hook::consts::signal;
-use signal_hook::iterator::Signals;
+use nix::sys::signal::{Signal, killpg};
+use nix::unistd::Pid;
+use tokio::signal::unix::{SignalKind, signal};
 use tokio::sync::{Notify, oneshot};
 
+use crate::config::command::utils::{
+    normalized_command::NormalizedCommand,
+    process::{Instance, OnExit},
+};
+use crate::errors::Error;
+use crate::worker::context::Binding;
+
+/// What started a child and how it is taken care of.
+#[derive(Debug, Clone, Default)]
+pub struct Origin {
+    pub binding: Binding,
+    /// Runs when the child exits unsuccessfully.
+    pub on_failure: Option<NormalizedCommand>,
+    /// Children started with `Instance::Multiple`, like `on_failure` commands, are never found by
+    /// `running`.
+    pub instance: Instance,
+    pub on_exit: OnExit,
+}
+
+/// A child that finished, as returned by `Children::reap`.
+#[derive(Debug)]
+pub struct Exited {
+    pub origin: Origin,
+    pub status: ExitStatus,
+    pub duration: Duration,
+    /// Whether lefthk stopped it, so a failing status is expected.
+    pub terminated: bool,
+}
+
+#[derive(Debug)]
+struct Tracked {
+    child: Child,
+    origin: Origin,
+    started: Instant,
+    terminated: bool,
+}
+
+impl From<Child> for Tracked {
+    fn from(child: Child) -> Self {
+        Self {
+            child,
+            origin: Origin::default(),
+            started: Instant::now(),
+            terminated: false,
+        }
+    }
+}
+
 /// A struct managing children processes.
 ///
 /// The `reap` method could be called at any place the user wants to.
@@ -16,7 +65,7 @@ use tokio::sync::{Notify, oneshot};
 /// flag to do a epoch-based reaping.
 #[derive(Debug)]
 pub struct Children {
-    inner: HashMap<u32, Child>,
+    inner: HashMap<u32, Tracked>,
     pub task_notify: Arc<Notify>,
     _task_guard: oneshot::Receiver<()>,
 }
@@ -33,19 +82,16 @@ impl Children {
     /// # Panics
     /// - Panics if SIGCHLD cannot be created.
     pub fn new() -> Self {
-        let (guard, task_guard) = oneshot::channel();
+        let (mut guard, task_guard) = oneshot::channel();
         let task_notify = Arc::new(Notify::new());
         let notify = task_notify.clone();
-        let mut signals = Signals::new([signal::SIGCHLD]).expect("Couldn't setup signals.");
-        tokio::task::spawn_blocking(move || {
+        let mut sigchld = signal(SignalKind::child()).expect("Couldn't setup signals.");
+        tokio::spawn(async move {
             loop {
-                if guard.is_closed() {
-                    return;
-                }
-                for _ in signals.pending() {
-                    notify.notify_one();
+                tokio::select! {
+                    () = guard.closed() => return,
+                    Some(()) = sigchld.recv() => notify.notify_one(),
                 }
-                std::thread::sleep(Duration::from_millis(100));
             }
         });
 
@@ -70,19 +116,128 @@ impl Children {
     /// ## Return value
     /// `false` if it's already registered, otherwise `true`
     pub fn insert(&mut self, child: Child) -> bool {
+        self.insert_from(child, Origin::default())
+    }
+
+    /// Add another child-process, remembering what started it.
+    /// ## Return value
+    /// `false` if it's already registered, otherwise `true`
+    pub fn insert_from(&mut self, child: Child, origin: Origin) -> bool {
+        let tracked = Tracked {
+            origin,
+            ..Tracked::from(child)
+        };
         // Not possible to have duplication!
-        self.inner.insert(child.id(), child).is_none()
+        self.inner.insert(tracked.child.id(), tracked).is_none()
     }
 
     /// Merge another `Children` into this `Children`.
     pub fn merge(&mut self, reaper: Self) {
         self.inner.extend(reaper.inner);
+        // Some of them might have exited without this `Children` getting a SIGCHLD.
+        self.task_notify.notify_one();
     }
 
-    /// Remove all children which finished
-    pub fn reap(&mut self) {
+    /// Remove all children which finished, returning how they did
+    pub fn reap(&mut self) -> Vec<Exited> {
+        let mut exited = Vec::new();
         self.inner
-            .retain(|_, child| child.try_wait().map_or(true, |ret| ret.is_none()));
+            .retain(|_, tracked| match tracked.child.try_wait() {
+                Ok(Some(status)) => {
+                    exited.push(Exited {
+                        origin: tracked.origin.clone(),
+                        status,
+                        duration: tracked.started.elapsed(),
+                        terminated: tracked.terminated,
+                    });
+                    false
+                }
+                Ok(None) | Err(_) => true,
+            });
+        exited
+    }
+
+    /// The ids of the children still running that were started by the keybind at the chord
+    /// path, with an instance policy.
+    pub fn running(&mut self, path: &[String]) -> Vec<u32> {
+        if path.is_empty() {
+            return Vec::new();
+        }
+        self.inner
+            .iter_mut()
+            .filter_map(|(id, tracked)| {
+                let running = !tracked.terminated
+                    && !tracked.origin.instance.is_multiple()
+                    && tracked.origin.binding.path == path
+                    && matches!(tracked.child.try_wait(), Ok(None));
+                running.then_some(*id)
+            })
+            .collect()
+    }
+
+    /// Sends SIGTERM to the process group of a child.
+    /// # Errors
+    ///
+    /// Will error if the signal can't be sent.
+    pub fn terminate(&mut self, id: u32) -> Error {
+        if let Some(tracked) = self.inner.get_mut(&id) {
+            killpg(Pid::from_raw(id as i32), Signal::SIGTERM)?;
+            tracked.terminated = true;
+        }
+        Ok(())
+    }
+
+    /// Stops the children that shouldn't outlive lefthk, following their `OnExit`. Waits for the
+    /// ones with a grace period to exit and kills them once it's over.
+    ///
+    /// Every child stays tracked until it's reaped, so merging this into the `Children` of the
+    /// next worker keeps their exit status on a reload.
+    pub async fn shutdown(&mut self) {
+        let started = Instant::now();
+        let mut pending = Vec::new();
+        for (id, tracked) in &mut self.inner {
+            let grace = match tracked.origin.on_exit {
+                OnExit::Detach => continue,
+                OnExit::Terminate => None,
+                OnExit::Kill(grace) => Some(Duration::from_millis(grace)),
+            };
+            if !matches!(tracked.child.try_wait(), Ok(None)) {
+                continue;
+            }
+            if let Err(err) = killpg(Pid::from_raw(*id as i32), Signal::SIGTERM) {
+                tracing::error!(
+                    "Unable to terminate {}: {}",
+                    tracked.origin.binding.label(),
+                    err
+                );
+                continue;
+            }
+            tracked.terminated = true;
+            pending.extend(grace.map(|grace| (*id, grace)));
+        }
+        loop {
+            pending.retain(|(id, grace)| {
+                let Some(tracked) = self.inner.get_mut(id) else {
+                    return false;
+                };
+                if !matches!(tracked.child.try_wait(), Ok(None)) {
+                    return false;
+                }
+                if started.elapsed() < *grace {
+                    return true;
+                }
+                if let Err(err) = killpg(Pid::from_raw(*id as i32), Signal::SIGKILL) {
+                    tracing::error!("Unable to kill {}: {}", tracked.origin.binding.label(), err);
+                }
+                false
+            });
+            let Some(deadline) = pending.iter().map(|(_, grace)| started + *grace).min() else {
+                break;
+            };
+            // SIGCHLD wakes this up as soon as one of them exits.
+            let deadline = tokio::time::Instant::from_std(deadline);
+            let _ = tokio::time::timeout_at(deadline, self.task_notify.notified()).await;
+        }
     }
 
     pub fn wait_readable(&mut self) -> Pin<Box<dyn Future<Output = ()>>> {
@@ -95,7 +250,9 @@ impl Children {
 
 impl Extend<Child> for Children {
     fn extend<T: IntoIterator<Item = Child>>(&mut self, iter: T) {
-        self.inner
-            .extend(iter.into_iter().map(|child| (child.id(), child)));
+        self.inner.extend(
+            iter.into_iter()
+                .map(|child| (child.id(), Tracked::from(child))),
+        );
     }
 }
@@ -34,6 +34,7 @@ impl Command for Chord {
     fn execute(&self, worker: &mut Worker) -> Error {
         worker.xwrap.grab_keys(&self.0);
         worker.chord_ctx.keybinds = Some(self.0.clone());
+        worker.chord_ctx.path.clone_from(&worker.binding_ctx.path);
         Ok(())
     }
 
@@ -54,6 +55,8 @@ mod tests {
             command: Reload::new().normalize(),
             modifier: vec![],
             key: String::new(),
+            name: Some("reload".to_string()),
+            description: Some("Reload the config".to_string()),
         }]);
 
         let normalized = command.normalize();
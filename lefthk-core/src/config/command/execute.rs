@@ -1,5 +1,3 @@
-use std::process::Stdio;
-
 use ron::ser::PrettyConfig;
 use serde::{Deserialize, Serialize};
 
@@ -8,16 +6,25 @@ use crate::{
     worker::Worker,
 };
 
-use super::{Command, NormalizedCommand};
+use super::{Command, NormalizedCommand, utils::process::Process};
 
 inventory::submit! {DenormalizeCommandFunction::new::<Execute>()}
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct Execute(String);
+pub struct Execute(
+    String,
+    #[serde(default, skip_serializing_if = "Process::is_default")] Process,
+);
 
 impl Execute {
     pub fn new<T: ToString>(shell_command: &T) -> Self {
-        Self(shell_command.to_string())
+        Self(shell_command.to_string(), Process::default())
+    }
+
+    #[must_use]
+    pub fn with_process(mut self, process: Process) -> Self {
+        self.1 = process;
+        self
     }
 }
 
@@ -34,17 +41,9 @@ impl Command for Execute {
 
     fn execute(&self, worker: &mut Worker) -> Error {
         worker.chord_ctx.elapsed = worker.chord_ctx.keybinds.is_some();
-        let child = std::process::Command::new("sh")
-            .arg("-c")
-            .arg(&self.0)
-            .stdin(Stdio::null())
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .spawn()?;
-
-        worker.children.insert(child);
-
-        Ok(())
+        let mut command = std::process::Command::new("sh");
+        command.arg("-c").arg(&self.0);
+        self.1.spawn(command, worker)
     }
 
     fn get_name(&self) -> &'static str {
@@ -54,7 +53,13 @@ impl Command for Execute {
 
 #[cfg(test)]
 mod tests {
-    use crate::config::Command;
+    use crate::config::{
+        Command,
+        command::utils::{
+            output::Output,
+            process::{Instance, OnExit, Process},
+        },
+    };
 
     use super::Execute;
 
@@ -71,4 +76,29 @@ mod tests {
             "{normalized:?}, {denormalized:?}",
         );
     }
+
+    #[test]
+    fn normalize_process_options() {
+        let plain = Execute::new(&"make");
+        assert_eq!(plain.normalize().0, "Execute(\"make\")");
+
+        let command = plain.with_process(Process {
+            env: [("RUST_LOG".to_string(), "debug".to_string())].into(),
+            clear_env: true,
+            cwd: Some("/tmp".to_string()),
+            output: Output::File("make.log".to_string()),
+            on_failure: Some(Execute::new(&"notify-send failed").normalize()),
+            instance: Instance::Toggle,
+            on_exit: OnExit::Kill(500),
+        });
+
+        let normalized = command.normalize();
+        let denormalized = Execute::denormalize(&normalized).unwrap();
+
+        assert_eq!(
+            Box::new(command),
+            denormalized,
+            "{normalized:?}, {denormalized:?}",
+        );
+    }
 }
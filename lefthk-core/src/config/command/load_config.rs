@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::command::utils::denormalize_function::DenormalizeCommandFunction,
+    errors::Error,
+    worker::{self, Worker},
+};
+
+use super::{Command, NormalizedCommand};
+
+inventory::submit! {DenormalizeCommandFunction::new::<LoadConfig>()}
+
+/// Reload the daemon with the config file at the given path.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LoadConfig(PathBuf);
+
+impl LoadConfig {
+    pub fn new(config_file: PathBuf) -> Self {
+        Self(config_file)
+    }
+}
+
+impl Command for LoadConfig {
+    fn normalize(&self) -> NormalizedCommand {
+        let serialized_string =
+            ron::ser::to_string_pretty(self, PrettyConfig::new().struct_names(true)).unwrap();
+        NormalizedCommand(serialized_string)
+    }
+
+    fn denormalize(generalized: &NormalizedCommand) -> Option<Box<Self>> {
+        ron::from_str(&generalized.0).ok()
+    }
+
+    fn execute(&self, worker: &mut Worker) -> Error {
+        worker.status = worker::Status::LoadConfig(self.0.clone());
+        Ok(())
+    }
+
+    fn get_name(&self) -> &'static str {
+        "LoadConfig"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::config::{Command, command::Execute};
+
+    use super::LoadConfig;
+
+    #[test]
+    fn normalize_process() {
+        let command = LoadConfig::new(PathBuf::from("/tmp/lefthk/config.ron"));
+
+        let normalized = command.normalize();
+        let denormalized = LoadConfig::denormalize(&normalized).unwrap();
+
+        assert_eq!(
+            Box::new(command.clone()),
+            denormalized,
+            "{command:?}, {denormalized:?}",
+        );
+    }
+
+    #[test]
+    fn does_not_match_execute() {
+        let normalized = Execute::new(&"/tmp/lefthk/config.ron").normalize();
+
+        assert!(LoadConfig::denormalize(&normalized).is_none());
+    }
+}
@@ -2,7 +2,10 @@ mod chord;
 mod execute;
 mod exit_chord;
 mod kill;
+mod load_config;
 mod reload;
+mod spawn;
+mod trigger;
 
 pub mod utils;
 
@@ -12,7 +15,10 @@ use self::utils::{
 use crate::errors::{Error, LeftError, Result};
 use crate::worker::Worker;
 
-pub use self::{chord::Chord, execute::Execute, exit_chord::ExitChord, kill::Kill, reload::Reload};
+pub use self::{
+    chord::Chord, execute::Execute, exit_chord::ExitChord, kill::Kill, load_config::LoadConfig,
+    reload::Reload, spawn::Spawn, trigger::Trigger,
+};
 
 inventory::collect!(DenormalizeCommandFunction);
 
@@ -0,0 +1,79 @@
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::command::utils::denormalize_function::DenormalizeCommandFunction,
+    errors::{Error, LeftError},
+    worker::Worker,
+};
+
+use super::{Command, NormalizedCommand, utils::process::Process};
+
+inventory::submit! {DenormalizeCommandFunction::new::<Spawn>()}
+
+/// Runs a program with its arguments directly, without a shell in between.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Spawn(
+    Vec<String>,
+    #[serde(default, skip_serializing_if = "Process::is_default")] Process,
+);
+
+impl Spawn {
+    pub fn new<T: ToString>(argv: &[T]) -> Self {
+        Self(
+            argv.iter().map(ToString::to_string).collect(),
+            Process::default(),
+        )
+    }
+
+    #[must_use]
+    pub fn with_process(mut self, process: Process) -> Self {
+        self.1 = process;
+        self
+    }
+}
+
+impl Command for Spawn {
+    fn normalize(&self) -> NormalizedCommand {
+        let serialized_string =
+            ron::ser::to_string_pretty(self, PrettyConfig::new().struct_names(true)).unwrap();
+        NormalizedCommand(serialized_string)
+    }
+
+    fn denormalize(generalized: &NormalizedCommand) -> Option<Box<Self>> {
+        ron::from_str(&generalized.0).ok()
+    }
+
+    fn execute(&self, worker: &mut Worker) -> Error {
+        worker.chord_ctx.elapsed = worker.chord_ctx.keybinds.is_some();
+        let (program, args) = self.0.split_first().ok_or(LeftError::ProgramNotFound)?;
+        let mut command = std::process::Command::new(program);
+        command.args(args);
+        self.1.spawn(command, worker)
+    }
+
+    fn get_name(&self) -> &'static str {
+        "Spawn"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Command;
+
+    use super::Spawn;
+
+    #[test]
+    fn normalize_process() {
+        let command = Spawn::new(&["notify-send", "I use Arch; by the way"]);
+
+        let normalized = command.normalize();
+        let denormalized = Spawn::denormalize(&normalized).unwrap();
+
+        assert_eq!(
+            Box::new(command),
+            denormalized,
+            "{normalized:?}, {denormalized:?}",
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{command::utils::denormalize_function::DenormalizeCommandFunction, split_combo},
+    errors::{Error, LeftError},
+    worker::Worker,
+    xkeysym_lookup,
+};
+
+use super::{Command, NormalizedCommand};
+
+inventory::submit! {DenormalizeCommandFunction::new::<Trigger>()}
+
+/// Runs the keybind matching a combo like `Super+Shift+Return` or named like `terminal`, as if
+/// its keys were pressed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Trigger(String);
+
+impl Trigger {
+    pub fn new<T: ToString>(combo: &T) -> Self {
+        Self(combo.to_string())
+    }
+}
+
+impl Command for Trigger {
+    fn normalize(&self) -> NormalizedCommand {
+        let serialized_string =
+            ron::ser::to_string_pretty(self, PrettyConfig::new().struct_names(true)).unwrap();
+        NormalizedCommand(serialized_string)
+    }
+
+    fn denormalize(generalized: &NormalizedCommand) -> Option<Box<Self>> {
+        ron::from_str(&generalized.0).ok()
+    }
+
+    fn execute(&self, worker: &mut Worker) -> Error {
+        let combo = worker
+            .named_keybind(&self.0)
+            .map_or_else(|| self.0.clone(), ToString::to_string);
+        let (modifier, key) =
+            split_combo(&combo).ok_or_else(|| LeftError::InvalidCombo(combo.clone()))?;
+        let mask = xkeysym_lookup::try_into_modmask(&modifier)?;
+        let key = xkeysym_lookup::try_into_keysym(&key)?;
+        worker.execute_keybind(mask, key)
+    }
+
+    fn get_name(&self) -> &'static str {
+        "Trigger"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Command;
+
+    use super::Trigger;
+
+    #[test]
+    fn normalize_process() {
+        let command = Trigger::new(&"Super+Shift+Return");
+
+        let normalized = command.normalize();
+        let denormalized = Trigger::denormalize(&normalized).unwrap();
+
+        assert_eq!(
+            Box::new(command.clone()),
+            denormalized,
+            "{command:?}, {denormalized:?}",
+        );
+    }
+}
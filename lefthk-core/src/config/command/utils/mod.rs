@@ -1,2 +1,4 @@
 pub mod denormalize_function;
 pub mod normalized_command;
+pub mod output;
+pub mod process;
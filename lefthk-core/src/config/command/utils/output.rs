@@ -0,0 +1,203 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::{Child, Stdio},
+    sync::{Arc, LazyLock, Mutex, PoisonError, Weak},
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+
+use crate::{
+    errors::{Error, Result},
+    worker::Worker,
+};
+
+/// Log files are rotated once they grow beyond this size.
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+/// How many rotated log files are kept, as `<file>.1` up to `<file>.3`.
+const ROTATED_FILES: usize = 3;
+
+/// The log files that are still written to, shared by every process that outputs to them.
+static OPEN_FILES: LazyLock<Mutex<HashMap<PathBuf, Weak<Mutex<RotatingFile>>>>> =
+    LazyLock::new(Mutex::default);
+
+/// Where the stdout and stderr of a started process go.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Output {
+    /// Discards it.
+    #[default]
+    Null,
+    /// Shares the stdout and stderr of lefthk.
+    Inherit,
+    /// Logs each line, together with the keybind that started the process.
+    Log,
+    /// Appends it to a file, relative paths are in `$XDG_STATE_HOME/lefthk/`.
+    File(String),
+}
+
+impl Output {
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        *self == Self::Null
+    }
+
+    pub(crate) fn stdio(&self) -> Stdio {
+        match self {
+            Self::Null => Stdio::null(),
+            Self::Inherit => Stdio::inherit(),
+            Self::Log | Self::File(_) => Stdio::piped(),
+        }
+    }
+
+    /// Where the lines of the piped output go, opened before the process is started.
+    pub(crate) fn sink(&self, worker: &Worker) -> Result<Option<Sink>> {
+        Ok(match self {
+            Self::Null | Self::Inherit => None,
+            Self::Log => Some(Sink::Log(Arc::from(worker.binding_ctx.label()))),
+            Self::File(path) => Some(Sink::File(RotatingFile::shared(
+                worker.place_state_file(path)?,
+            )?)),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Sink {
+    Log(Arc<str>),
+    File(Arc<Mutex<RotatingFile>>),
+}
+
+impl Sink {
+    /// Forwards the stdout and stderr of the child line by line, until it closes them.
+    pub(crate) fn forward(self, child: &mut Child) -> Error {
+        if let Some(stdout) = child.stdout.take() {
+            let stdout = tokio::process::ChildStdout::from_std(stdout)?;
+            tokio::spawn(self.clone().forward_lines(stdout, false));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            let stderr = tokio::process::ChildStderr::from_std(stderr)?;
+            tokio::spawn(self.forward_lines(stderr, true));
+        }
+        Ok(())
+    }
+
+    async fn forward_lines(self, stream: impl AsyncRead + Unpin, is_stderr: bool) {
+        let mut lines = BufReader::new(stream).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            match &self {
+                Self::Log(label) if is_stderr => tracing::warn!("{}: {}", label, line),
+                Self::Log(label) => tracing::info!("{}: {}", label, line),
+                Self::File(file) => {
+                    let mut file = file.lock().unwrap_or_else(PoisonError::into_inner);
+                    if let Err(err) = file.write_line(&line) {
+                        tracing::error!("Unable to write to {}: {}", file.path.display(), err);
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A log file that is moved to `<file>.1` once it is too big, shifting older ones up.
+#[derive(Debug)]
+pub(crate) struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFile {
+    pub(crate) fn open(path: PathBuf) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self { path, file, size })
+    }
+
+    /// Opens the file, or returns the one that is already open for this path.
+    pub(crate) fn shared(path: PathBuf) -> io::Result<Arc<Mutex<Self>>> {
+        let mut open_files = OPEN_FILES.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(file) = open_files.get(&path).and_then(Weak::upgrade) {
+            return Ok(file);
+        }
+        open_files.retain(|_, file| file.strong_count() > 0);
+        let file = Arc::new(Mutex::new(Self::open(path.clone())?));
+        open_files.insert(path, Arc::downgrade(&file));
+        Ok(file)
+    }
+
+    pub(crate) fn write_line(&mut self, line: &str) -> io::Result<()> {
+        if self.size >= MAX_FILE_SIZE {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{line}")?;
+        self.size += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        for i in (1..ROTATED_FILES).rev() {
+            match fs::rename(self.rotated(i), self.rotated(i + 1)) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                _ => {}
+            }
+        }
+        fs::rename(&self.path, self.rotated(1))?;
+        *self = Self::open(self.path.clone())?;
+        Ok(())
+    }
+
+    fn rotated(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        path.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, sync::Arc};
+
+    use super::{MAX_FILE_SIZE, RotatingFile};
+
+    #[test]
+    fn rotate_by_size() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let path = dir.path().join("term.log");
+        let line = "x".repeat(1023);
+
+        let mut file = RotatingFile::open(path.clone()).unwrap();
+        // Rotates four times, dropping the oldest file once.
+        for _ in 0..MAX_FILE_SIZE / 1024 * 4 + 1 {
+            file.write_line(&line).unwrap();
+        }
+        file.write_line("last").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!("{line}\nlast\n")
+        );
+        for rotated in ["term.log.1", "term.log.2", "term.log.3"] {
+            let len = fs::metadata(dir.path().join(rotated)).unwrap().len();
+            assert_eq!(len, MAX_FILE_SIZE);
+        }
+        assert!(!dir.path().join("term.log.4").exists());
+    }
+
+    #[test]
+    fn share_open_files() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let path = dir.path().join("shared.log");
+
+        let first = RotatingFile::shared(path.clone()).unwrap();
+        let second = RotatingFile::shared(path.clone()).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        drop((first, second));
+        let reopened = RotatingFile::shared(path).unwrap();
+        assert_eq!(Arc::strong_count(&reopened), 1);
+    }
+}
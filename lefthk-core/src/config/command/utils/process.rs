@@ -0,0 +1,145 @@
+use std::{collections::BTreeMap, io, os::unix::process::CommandExt, process::Stdio};
+
+use super::{normalized_command::NormalizedCommand, output::Output};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{child::Origin, errors::Error, worker::Worker};
+
+/// How the process of an `Execute` or `Spawn` is set up.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Process {
+    /// Variables to set in its environment.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Starts from an empty environment instead of the one of lefthk.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub clear_env: bool,
+    /// The working directory, the one of lefthk if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    #[serde(default, skip_serializing_if = "Output::is_null")]
+    pub output: Output,
+    /// Runs when the process exits unsuccessfully.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_failure: Option<NormalizedCommand>,
+    #[serde(default, skip_serializing_if = "Instance::is_multiple")]
+    pub instance: Instance,
+    #[serde(default, skip_serializing_if = "OnExit::is_detach")]
+    pub on_exit: OnExit,
+}
+
+/// What happens when the keybind is pressed while a process it started is still running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Instance {
+    /// Starts another one.
+    #[default]
+    Multiple,
+    /// Doesn't start another one.
+    Single,
+    /// Stops the running one instead of starting another one.
+    Toggle,
+    /// Stops the running one and starts a new one.
+    Restart,
+}
+
+impl Instance {
+    #[must_use]
+    pub fn is_multiple(&self) -> bool {
+        *self == Self::Multiple
+    }
+}
+
+/// What happens to a started process when lefthk quits or reloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum OnExit {
+    /// Keeps it running in a session of its own.
+    #[default]
+    Detach,
+    /// Sends it SIGTERM.
+    Terminate,
+    /// Sends it SIGTERM, then SIGKILL if it still runs after the grace period in milliseconds.
+    Kill(u64),
+}
+
+impl OnExit {
+    #[must_use]
+    pub fn is_detach(&self) -> bool {
+        *self == Self::Detach
+    }
+}
+
+impl Process {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Sets up and starts the command, and hands the child to the worker.
+    pub(crate) fn spawn(&self, mut command: std::process::Command, worker: &mut Worker) -> Error {
+        let running = if self.instance.is_multiple() {
+            Vec::new()
+        } else {
+            worker.children.running(&worker.binding_ctx.path)
+        };
+        if !running.is_empty() {
+            match self.instance {
+                Instance::Multiple => {}
+                Instance::Single => {
+                    tracing::debug!("{} is still running", worker.binding_ctx.label());
+                    return Ok(());
+                }
+                Instance::Toggle | Instance::Restart => {
+                    for id in running {
+                        worker.children.terminate(id)?;
+                    }
+                    if self.instance == Instance::Toggle {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        if self.clear_env {
+            command.env_clear();
+        }
+        for (name, value) in worker.binding_ctx.env() {
+            match value {
+                Some(value) => command.env(name, value),
+                None => command.env_remove(name),
+            };
+        }
+        command.envs(&self.env);
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+        // Its own process group, so stopping it also stops what it started, e.g. behind `sh -c`.
+        match self.on_exit {
+            // SAFETY: `setsid` is async-signal-safe and touches no memory of the parent.
+            OnExit::Detach => unsafe {
+                command.pre_exec(|| nix::unistd::setsid().map(drop).map_err(io::Error::from));
+            },
+            OnExit::Terminate | OnExit::Kill(_) => {
+                command.process_group(0);
+            }
+        }
+        let sink = self.output.sink(worker)?;
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(self.output.stdio())
+            .stderr(self.output.stdio())
+            .spawn()?;
+        if let Some(sink) = sink {
+            sink.forward(&mut child)?;
+        }
+
+        let origin = Origin {
+            binding: worker.binding_ctx.clone(),
+            on_failure: self.on_failure.clone(),
+            instance: self.instance,
+            on_exit: self.on_exit,
+        };
+        worker.children.insert_from(child, origin);
+
+        Ok(())
+    }
+}
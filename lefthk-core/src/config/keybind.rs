@@ -1,3 +1,5 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 use super::command::utils::normalized_command::NormalizedCommand;
@@ -7,4 +9,69 @@ pub struct Keybind {
     pub command: NormalizedCommand,
     pub modifier: Vec<String>,
     pub key: String,
+    /// A stable name like `"terminal"`, to refer to the keybind from tooling.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// What the keybind does, like `"Open terminal"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Formats the keybind as a combo, e.g. `Super+Shift+Return`.
+impl fmt::Display for Keybind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for modifier in &self.modifier {
+            write!(f, "{modifier}+")?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+/// Splits a combo like `Super+Shift+Return` into its modifiers and key.
+///
+/// Returns `None` if any part of the combo is empty.
+#[must_use]
+pub fn split_combo(combo: &str) -> Option<(Vec<String>, String)> {
+    let mut parts: Vec<String> = combo
+        .split('+')
+        .map(|part| part.trim().to_owned())
+        .collect();
+    if parts.iter().any(String::is_empty) {
+        return None;
+    }
+    let key = parts.pop()?;
+    Some((parts, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::command::utils::normalized_command::NormalizedCommand;
+
+    use super::{Keybind, split_combo};
+
+    #[test]
+    fn split_combos() {
+        assert_eq!(
+            split_combo("Super+Shift+Return"),
+            Some((
+                vec!["Super".to_string(), "Shift".to_string()],
+                "Return".to_string()
+            ))
+        );
+        assert_eq!(split_combo(" x "), Some((vec![], "x".to_string())));
+        assert_eq!(split_combo("Super+"), None);
+        assert_eq!(split_combo("Super++x"), None);
+    }
+
+    #[test]
+    fn display_as_combo() {
+        let keybind = Keybind {
+            command: NormalizedCommand(String::new()),
+            modifier: vec!["Super".to_string(), "Shift".to_string()],
+            key: "Return".to_string(),
+            name: None,
+            description: None,
+        };
+        assert_eq!(keybind.to_string(), "Super+Shift+Return");
+    }
 }
@@ -2,7 +2,7 @@ pub mod command;
 mod keybind;
 
 pub use command::Command;
-pub use keybind::Keybind;
+pub use keybind::{Keybind, split_combo};
 
 pub trait Config {
     fn mapped_bindings(&self) -> Vec<Keybind>;
@@ -0,0 +1,164 @@
+use std::ffi::{OsStr, OsString};
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use inotify::{Event, Inotify, WatchDescriptor, WatchMask};
+use tokio::io::unix::AsyncFd;
+use tokio::sync::{Notify, oneshot};
+
+use crate::errors::{LeftError, Result};
+
+/// How long the config has to stay untouched before a change is reported. Editors tend to write
+/// a file in several steps, this makes sure we only reload once the file is complete.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Watches config files for changes using inotify.
+///
+/// Both the files and their directories are watched, so editors that save by writing a temporary
+/// file and renaming it over the original are picked up as well.
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    pub task_notify: Arc<Notify>,
+    _task_guard: oneshot::Receiver<()>,
+}
+
+impl ConfigWatcher {
+    /// Start watching the given config files, e.g. a config and the ones it includes.
+    /// # Errors
+    ///
+    /// Will error if inotify could not be set up or a directory containing one of the config
+    /// files can't be watched.
+    pub fn new(config_files: &[PathBuf]) -> Result<Self> {
+        let inotify = Inotify::init()?;
+        let files = config_files
+            .iter()
+            .map(|config_file| WatchedFile::new(&inotify, config_file))
+            .collect::<Result<_>>()?;
+
+        let (mut guard, task_guard) = oneshot::channel();
+        let task_notify = Arc::new(Notify::new());
+        let mut watch = Watch {
+            inotify: AsyncFd::new(inotify)?,
+            files,
+        };
+        let notify = task_notify.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                () = guard.closed() => {}
+                Err(err) = watch.run(&notify) => {
+                    tracing::error!("Stopped watching the config file: {}", err);
+                }
+            }
+        });
+
+        Ok(Self {
+            task_notify,
+            _task_guard: task_guard,
+        })
+    }
+
+    pub fn wait_readable(&self) -> Pin<Box<dyn Future<Output = ()>>> {
+        let task_notify = self.task_notify.clone();
+        Box::pin(async move {
+            task_notify.notified().await;
+        })
+    }
+}
+
+struct WatchedFile {
+    path: PathBuf,
+    file_name: OsString,
+    directory_watch: WatchDescriptor,
+    file_watch: Option<WatchDescriptor>,
+}
+
+impl WatchedFile {
+    fn new(inotify: &Inotify, config_file: &Path) -> Result<Self> {
+        let directory = config_file.parent().ok_or(LeftError::NoConfigFound)?;
+        let file_name = config_file
+            .file_name()
+            .ok_or(LeftError::NoConfigFound)?
+            .to_owned();
+        // A directory is only watched once, adding it again returns the same descriptor.
+        let directory_watch = inotify.watches().add(
+            directory,
+            WatchMask::CLOSE_WRITE
+                | WatchMask::CREATE
+                | WatchMask::DELETE
+                | WatchMask::MOVED_FROM
+                | WatchMask::MOVED_TO,
+        )?;
+        Ok(Self {
+            path: config_file.to_owned(),
+            file_name,
+            directory_watch,
+            file_watch: watch_file(inotify, config_file),
+        })
+    }
+
+    fn is_changed_by(&self, event: &Event<&OsStr>) -> bool {
+        match event.name {
+            Some(name) => event.wd == self.directory_watch && name == self.file_name,
+            None => Some(&event.wd) == self.file_watch.as_ref(),
+        }
+    }
+}
+
+struct Watch {
+    inotify: AsyncFd<Inotify>,
+    files: Vec<WatchedFile>,
+}
+
+impl Watch {
+    async fn run(&mut self, notify: &Notify) -> io::Result<()> {
+        let mut buffer = [0; 4096];
+        loop {
+            if !self.next_events(&mut buffer).await? {
+                continue;
+            }
+            while let Ok(events) =
+                tokio::time::timeout(DEBOUNCE, self.next_events(&mut buffer)).await
+            {
+                events?;
+            }
+            // The files might have been replaced, in that case the old watches are gone.
+            for file in &mut self.files {
+                file.file_watch = watch_file(self.inotify.get_ref(), &file.path);
+            }
+            tracing::info!("Config file changed");
+            notify.notify_one();
+        }
+    }
+
+    /// Waits for the next batch of events, returns whether any of them concern a config file.
+    async fn next_events(&mut self, buffer: &mut [u8]) -> io::Result<bool> {
+        loop {
+            let mut guard = self.inotify.readable_mut().await?;
+            let read = guard.try_io(|inotify| {
+                inotify.get_mut().read_events(buffer).map(|mut events| {
+                    events.any(|event| self.files.iter().any(|file| file.is_changed_by(&event)))
+                })
+            });
+            if let Ok(result) = read {
+                return result;
+            }
+        }
+    }
+}
+
+fn watch_file(inotify: &Inotify, config_file: &Path) -> Option<WatchDescriptor> {
+    inotify
+        .watches()
+        .add(
+            config_file,
+            WatchMask::CLOSE_WRITE
+                | WatchMask::MODIFY
+                | WatchMask::DELETE_SELF
+                | WatchMask::MOVE_SELF,
+        )
+        .ok()
+}
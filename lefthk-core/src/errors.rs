@@ -1,3 +1,5 @@
+use std::fmt;
+
 use thiserror::Error;
 
 // TODO: Replace with expr_2024
@@ -30,6 +32,7 @@ pub type Result<T> = std::result::Result<T, LeftError>;
 pub type Error = std::result::Result<(), LeftError>;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum LeftError {
     #[error("IO error: {0}.")]
     IoError(#[from] std::io::Error),
@@ -44,14 +47,37 @@ pub enum LeftError {
     UnmatchingCommand,
     #[error("No command found for keybind.")]
     CommandNotFound,
-    #[error("No key found for keybind.")]
-    KeyNotFound,
-    #[error("No modifier found for keybind.")]
-    ModifierNotFound,
+    #[error("Invalid combo {0:?}, expected something like \"Super+Shift+Return\".")]
+    InvalidCombo(String),
+    #[error("Unknown key {0:?}.{1}")]
+    KeyNotFound(String, Suggestion),
+    #[error("Unknown modifier {0:?}.{1}")]
+    ModifierNotFound(String, Suggestion),
     #[error("No config file found.")]
     NoConfigFound,
+    #[error("No program set to spawn.")]
+    ProgramNotFound,
     #[error("No value set for execution.")]
     ValueNotFound,
     #[error("X failed status error.")]
     XFailedStatus,
 }
+
+/// The closest known name to an unknown key or modifier, if there is one that is close enough.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Suggestion(pub Option<String>);
+
+impl From<Option<&str>> for Suggestion {
+    fn from(name: Option<&str>) -> Self {
+        Self(name.map(ToOwned::to_owned))
+    }
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(name) => write!(f, " Did you mean {name:?}?"),
+            None => Ok(()),
+        }
+    }
+}
@@ -2,6 +2,7 @@ mod tests;
 
 pub mod child;
 pub mod config;
+pub mod config_watcher;
 pub mod errors;
 pub mod ipc;
 pub mod worker;
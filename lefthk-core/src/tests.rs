@@ -45,3 +45,275 @@ mod ipc {
         assert_eq!(command.normalize(), denormalized.normalize());
     }
 }
+
+/// Children Testing
+#[cfg(test)]
+mod children {
+    use std::io::{BufRead, BufReader};
+    use std::os::unix::process::{CommandExt, ExitStatusExt};
+    use std::process::{Command, Stdio};
+    use std::time::Duration;
+
+    use crate::child::{Children, Exited, Origin};
+    use crate::config::command::Reload;
+    use crate::config::command::utils::process::{Instance, OnExit};
+    use crate::config::{Command as _, command::utils::normalized_command::NormalizedCommand};
+    use crate::worker::context::Binding;
+
+    /// Reaps until `count` children exited, waking up on SIGCHLD and giving up after 5 seconds.
+    async fn reap(children: &mut Children, count: usize) -> Vec<Exited> {
+        let mut exited = Vec::new();
+        for _ in 0..250 {
+            exited.extend(children.reap());
+            if exited.len() >= count {
+                break;
+            }
+            // A SIGCHLD may also come from a child of another test, so don't rely on it alone.
+            let _ = tokio::time::timeout(Duration::from_millis(20), children.wait_readable()).await;
+        }
+        exited
+    }
+
+    #[tokio::test]
+    async fn report_exit_status() {
+        let mut children = Children::new();
+        let on_failure: NormalizedCommand = Reload::new().normalize();
+        let child = Command::new("sh").arg("-c").arg("exit 3").spawn().unwrap();
+        children.insert_from(
+            child,
+            Origin {
+                on_failure: Some(on_failure.clone()),
+                ..Origin::default()
+            },
+        );
+
+        let exited = reap(&mut children, 1).await;
+
+        assert_eq!(exited.len(), 1);
+        assert_eq!(exited[0].status.code(), Some(3));
+        assert_eq!(exited[0].origin.on_failure, Some(on_failure));
+        assert!(!exited[0].terminated);
+        assert!(children.is_empty());
+    }
+
+    #[tokio::test]
+    async fn wake_up_on_sigchld() {
+        let mut children = Children::new();
+        children.insert(Command::new("true").spawn().unwrap());
+
+        // Children of other tests may wake it up before this one exits.
+        let mut exited = Vec::new();
+        while exited.is_empty() {
+            tokio::time::timeout(Duration::from_secs(5), children.wait_readable())
+                .await
+                .expect("SIGCHLD didn't wake up the children");
+            exited = children.reap();
+        }
+        assert_eq!(exited.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn terminate_running_child() {
+        let mut children = Children::new();
+        let path = vec!["Super+t".to_string()];
+        let child = Command::new("sleep")
+            .arg("10")
+            .process_group(0)
+            .spawn()
+            .unwrap();
+        let id = child.id();
+        let binding = Binding {
+            keybind: None,
+            path: path.clone(),
+        };
+        children.insert_from(
+            child,
+            Origin {
+                binding: binding.clone(),
+                instance: Instance::Toggle,
+                ..Origin::default()
+            },
+        );
+        // Like an `on_failure` command of the same keybind.
+        let on_failure = Command::new("sleep").arg("10").spawn().unwrap();
+        let on_failure_id = on_failure.id();
+        children.insert_from(
+            on_failure,
+            Origin {
+                binding,
+                ..Origin::default()
+            },
+        );
+        assert_eq!(children.running(&path), vec![id]);
+        assert!(children.running(&["Super+s".to_string()]).is_empty());
+        nix::sys::signal::kill(
+            nix::unistd::Pid::from_raw(on_failure_id as i32),
+            nix::sys::signal::Signal::SIGKILL,
+        )
+        .unwrap();
+
+        children.terminate(id).unwrap();
+        assert!(children.running(&path).is_empty());
+        let exited = reap(&mut children, 2).await;
+        assert_eq!(exited.len(), 2);
+        let terminated: Vec<&Exited> = exited.iter().filter(|exited| exited.terminated).collect();
+        assert_eq!(terminated.len(), 1);
+        assert_eq!(terminated[0].origin.instance, Instance::Toggle);
+        assert!(!terminated[0].status.success());
+    }
+
+    #[tokio::test]
+    async fn shutdown_by_on_exit() {
+        let mut children = Children::new();
+        let mut spawn = |script: &str, on_exit: OnExit| {
+            let mut child = Command::new("sh")
+                .arg("-c")
+                .arg(format!("{script}; echo started; sleep 10"))
+                .process_group(0)
+                .stdout(Stdio::piped())
+                .spawn()
+                .unwrap();
+            // Makes sure the trap is set before the shutdown.
+            let mut started = String::new();
+            BufReader::new(child.stdout.take().unwrap())
+                .read_line(&mut started)
+                .unwrap();
+            let id = child.id();
+            let origin = Origin {
+                on_exit,
+                ..Origin::default()
+            };
+            children.insert_from(child, origin);
+            id
+        };
+        spawn("true", OnExit::Terminate);
+        spawn("trap '' TERM", OnExit::Kill(100));
+        let detached = spawn("true", OnExit::Detach);
+
+        children.shutdown().await;
+        let exited = reap(&mut children, 2).await;
+
+        let signal = |exited: &Exited| exited.status.signal();
+        assert_eq!(exited.len(), 2);
+        for exited in &exited {
+            assert!(exited.terminated);
+        }
+        assert_eq!(exited.iter().map(signal).min(), Some(Some(9)));
+        assert_eq!(exited.iter().map(signal).max(), Some(Some(15)));
+        // Only the detached child is left running.
+        assert_eq!(children.len(), 1);
+
+        // The next worker reaps it after a reload.
+        let mut next = Children::new();
+        next.merge(children);
+        next.terminate(detached).unwrap();
+        let exited = reap(&mut next, 1).await;
+        assert_eq!(exited.len(), 1);
+        assert!(next.is_empty());
+    }
+}
+
+/// Config Watcher Testing
+#[cfg(test)]
+mod config_watcher {
+    use std::time::Duration;
+
+    use crate::config_watcher::ConfigWatcher;
+
+    #[tokio::test]
+    async fn detects_save_by_rename() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let config_file = dir.path().join("config.ron");
+        std::fs::write(&config_file, "Config()").unwrap();
+        let watcher = ConfigWatcher::new(std::slice::from_ref(&config_file)).unwrap();
+
+        let temp_file = dir.path().join("config.ron~");
+        std::fs::write(&temp_file, "Config(keybinds: [])").unwrap();
+        std::fs::rename(&temp_file, &config_file).unwrap();
+
+        let changed = tokio::time::timeout(Duration::from_secs(5), watcher.wait_readable()).await;
+        assert!(changed.is_ok());
+    }
+
+    #[tokio::test]
+    async fn ignores_other_files() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let config_file = dir.path().join("config.ron");
+        std::fs::write(&config_file, "Config()").unwrap();
+        let watcher = ConfigWatcher::new(&[config_file]).unwrap();
+
+        std::fs::write(dir.path().join("other.ron"), "Config()").unwrap();
+
+        let changed = tokio::time::timeout(Duration::from_secs(1), watcher.wait_readable()).await;
+        assert!(changed.is_err());
+    }
+
+    #[tokio::test]
+    async fn watches_every_file() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let config_file = dir.path().join("config.ron");
+        let included_dir = dir.path().join("included");
+        let included_file = included_dir.join("media.ron");
+        std::fs::create_dir(&included_dir).unwrap();
+        std::fs::write(&config_file, "Config()").unwrap();
+        std::fs::write(&included_file, "Config()").unwrap();
+        let watcher = ConfigWatcher::new(&[config_file, included_file.clone()]).unwrap();
+
+        // Same name as the main config, but in the directory of the included one.
+        std::fs::write(included_dir.join("config.ron"), "Config()").unwrap();
+        let changed = tokio::time::timeout(Duration::from_secs(1), watcher.wait_readable()).await;
+        assert!(changed.is_err());
+
+        std::fs::write(&included_file, "Config(keybinds: [])").unwrap();
+        let changed = tokio::time::timeout(Duration::from_secs(5), watcher.wait_readable()).await;
+        assert!(changed.is_ok());
+    }
+}
+
+/// Key Lookup Testing
+#[cfg(test)]
+mod xkeysym_lookup {
+    use x11_dl::{keysym, xlib};
+
+    use crate::errors::{LeftError, Suggestion};
+    use crate::xkeysym_lookup::{try_into_keysym, try_into_modmask};
+
+    #[test]
+    fn known_names() {
+        assert_eq!(try_into_keysym("Return").unwrap(), keysym::XK_Return);
+        assert_eq!(
+            try_into_modmask(&["Super".to_string(), "Shift".to_string()]).unwrap(),
+            xlib::Mod4Mask | xlib::ShiftMask
+        );
+        assert_eq!(try_into_modmask(&["None".to_string()]).unwrap(), 0);
+    }
+
+    #[test]
+    fn suggest_closest_key() {
+        let err = try_into_keysym("Retrun").unwrap_err();
+        assert!(matches!(
+            &err,
+            LeftError::KeyNotFound(name, Suggestion(Some(suggestion)))
+                if name == "Retrun" && suggestion == "Return"
+        ));
+        assert_eq!(
+            err.to_string(),
+            r#"Unknown key "Retrun". Did you mean "Return"?"#
+        );
+    }
+
+    #[test]
+    fn suggest_closest_modifier() {
+        let err = try_into_modmask(&["Mod4".to_string(), "Ctrl".to_string()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Unknown modifier "Ctrl". Did you mean "Control"?"#
+        );
+    }
+
+    #[test]
+    fn no_suggestion_for_nonsense() {
+        let err = try_into_keysym("qqqqqqqqqqqqqqqqqqqqqqqq").unwrap_err();
+        assert!(matches!(err, LeftError::KeyNotFound(_, Suggestion(None))));
+    }
+}
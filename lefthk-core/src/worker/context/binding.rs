@@ -0,0 +1,88 @@
+use crate::config::Keybind;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Binding {
+    pub keybind: Option<Keybind>,
+    /// The combos pressed to get to the keybind, including its own.
+    pub path: Vec<String>,
+}
+
+impl Binding {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The name of the keybind, or its combo if it has none.
+    #[must_use]
+    pub fn label(&self) -> String {
+        match &self.keybind {
+            Some(Keybind {
+                name: Some(name), ..
+            }) => name.clone(),
+            Some(keybind) => keybind.to_string(),
+            None => "Command".to_string(),
+        }
+    }
+
+    /// The variables describing the keybind, `None` for the ones to unset so they aren't
+    /// inherited from the environment of lefthk.
+    #[must_use]
+    pub fn env(&self) -> Vec<(&'static str, Option<String>)> {
+        let keybind = self.keybind.as_ref();
+        vec![
+            ("LEFTHK_KEY", keybind.map(|kb| kb.key.clone())),
+            ("LEFTHK_MODIFIERS", keybind.map(|kb| kb.modifier.join("+"))),
+            ("LEFTHK_CHORD_PATH", keybind.map(|_| self.path.join(" "))),
+            (
+                "LEFTHK_BINDING_NAME",
+                keybind.and_then(|kb| kb.name.clone()),
+            ),
+            ("LEFTHK_PID", Some(std::process::id().to_string())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{Command, Keybind, command::Reload};
+
+    use super::Binding;
+
+    #[test]
+    fn describe_keybind() {
+        let binding = Binding {
+            keybind: Some(Keybind {
+                command: Reload::new().normalize(),
+                modifier: vec!["Super".to_string(), "Shift".to_string()],
+                key: "r".to_string(),
+                name: Some("reload".to_string()),
+                description: None,
+            }),
+            path: vec!["Super+c".to_string(), "Super+Shift+r".to_string()],
+        };
+        let pid = std::process::id().to_string();
+        assert_eq!(
+            binding.env(),
+            vec![
+                ("LEFTHK_KEY", Some("r".to_string())),
+                ("LEFTHK_MODIFIERS", Some("Super+Shift".to_string())),
+                (
+                    "LEFTHK_CHORD_PATH",
+                    Some("Super+c Super+Shift+r".to_string())
+                ),
+                ("LEFTHK_BINDING_NAME", Some("reload".to_string())),
+                ("LEFTHK_PID", Some(pid.clone())),
+            ]
+        );
+        assert_eq!(
+            Binding::new().env(),
+            vec![
+                ("LEFTHK_KEY", None),
+                ("LEFTHK_MODIFIERS", None),
+                ("LEFTHK_CHORD_PATH", None),
+                ("LEFTHK_BINDING_NAME", None),
+                ("LEFTHK_PID", Some(pid)),
+            ]
+        );
+    }
+}
@@ -4,6 +4,8 @@ use crate::{config::Keybind, worker::Worker};
 pub struct Chord {
     pub keybinds: Option<Vec<Keybind>>,
     pub elapsed: bool,
+    /// The combos pressed to enter the chord.
+    pub path: Vec<String>,
 }
 
 impl Chord {
@@ -11,6 +13,7 @@ impl Chord {
         Self {
             keybinds: None,
             elapsed: false,
+            path: Vec::new(),
         }
     }
 }
@@ -21,6 +24,7 @@ impl Worker {
             self.xwrap.grab_keys(&self.keybinds);
             self.chord_ctx.keybinds = None;
             self.chord_ctx.elapsed = false;
+            self.chord_ctx.path.clear();
         }
     }
 }
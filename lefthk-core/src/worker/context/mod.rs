@@ -1,3 +1,5 @@
+mod binding;
 mod chord;
 
+pub use binding::Binding;
 pub use chord::Chord;
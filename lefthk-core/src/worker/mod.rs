@@ -1,17 +1,22 @@
 pub mod context;
 
-use crate::child::Children;
-use crate::config::{Keybind, command};
+use crate::child::{Children, Exited};
+use crate::config::{Command, Keybind, command};
+use crate::config_watcher::ConfigWatcher;
 use crate::errors::{self, Error, LeftError};
 use crate::ipc::Pipe;
 use crate::xkeysym_lookup;
 use crate::xwrap::XWrap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
 use x11_dl::xlib;
 use xdg::BaseDirectories;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Status {
     Reload,
+    LoadConfig(PathBuf),
     Kill,
     Continue,
 }
@@ -23,24 +28,37 @@ pub struct Worker {
     pub xwrap: XWrap,
     pub children: Children,
     pub status: Status,
+    pub config_watcher: Option<ConfigWatcher>,
 
     /// "Chord Context": Holds the relevant data for chording
     pub chord_ctx: context::Chord,
+    /// "Binding Context": The keybind whose command is running
+    pub binding_ctx: context::Binding,
 }
 
 impl Worker {
     pub fn new(keybinds: Vec<Keybind>, base_directory: BaseDirectories) -> Self {
         Self {
             status: Status::Continue,
+            config_watcher: None,
             keybinds,
             base_directory,
             xwrap: XWrap::new(),
             children: Children::default(),
             chord_ctx: context::Chord::new(),
+            binding_ctx: context::Binding::new(),
         }
     }
 
-    pub async fn event_loop(mut self) -> Status {
+    /// Reload whenever one of the given config files changes.
+    pub fn watch_config(&mut self, config_files: &[PathBuf]) {
+        match ConfigWatcher::new(config_files) {
+            Ok(watcher) => self.config_watcher = Some(watcher),
+            Err(err) => tracing::error!("Unable to watch the config file: {}", err),
+        }
+    }
+
+    pub async fn event_loop(&mut self) -> Status {
         self.xwrap.grab_keys(&self.keybinds);
         let mut pipe = self.get_pipe().await;
 
@@ -51,7 +69,9 @@ impl Worker {
 
             tokio::select! {
                 () = self.children.wait_readable() => {
-                    self.children.reap();
+                    for exited in self.children.reap() {
+                        self.handle_exit(exited);
+                    }
                 }
                 () = self.xwrap.wait_readable() => {
                     let event_in_queue = self.xwrap.queue_len();
@@ -61,12 +81,16 @@ impl Worker {
                     }
                 }
                 Some(command) = pipe.get_next_command() => {
-                    errors::log_on_error!(command.execute(&mut self));
+                    errors::log_on_error!(command.execute(self));
+                }
+                () = self.wait_config_changed() => {
+                    errors::log_on_error!(command::Reload::new().execute(self));
                 }
             };
         }
 
-        self.status
+        self.children.shutdown().await;
+        self.status.clone()
     }
 
     async fn get_pipe(&self) -> Pipe {
@@ -75,6 +99,38 @@ impl Worker {
         errors::exit_on_error!(Pipe::new(pipe_file).await)
     }
 
+    fn wait_config_changed(&self) -> Pin<Box<dyn Future<Output = ()>>> {
+        match &self.config_watcher {
+            Some(watcher) => watcher.wait_readable(),
+            None => Box::pin(std::future::pending()),
+        }
+    }
+
+    /// Logs how a child finished and runs its `on_failure` command if it failed.
+    fn handle_exit(&mut self, exited: Exited) {
+        let label = exited.origin.binding.label();
+        if exited.status.success() || exited.terminated {
+            tracing::debug!("{} finished after {:?}", label, exited.duration);
+            return;
+        }
+        tracing::warn!(
+            "{} failed with {} after {:?}",
+            label,
+            exited.status,
+            exited.duration
+        );
+        if let Some(on_failure) = exited.origin.on_failure {
+            // Run as part of the failed keybind, without leaving a chord entered meanwhile.
+            let binding = std::mem::replace(&mut self.binding_ctx, exited.origin.binding);
+            let elapsed = self.chord_ctx.elapsed;
+            errors::log_on_error!(
+                command::denormalize(&on_failure).and_then(|command| command.execute(self))
+            );
+            self.binding_ctx = binding;
+            self.chord_ctx.elapsed = elapsed;
+        }
+    }
+
     fn handle_event(&mut self, xlib_event: &xlib::XEvent) {
         let error = match xlib_event.get_type() {
             xlib::KeyPress => self.handle_key_press(&xlib::XKeyEvent::from(xlib_event)),
@@ -89,7 +145,30 @@ impl Worker {
     fn handle_key_press(&mut self, event: &xlib::XKeyEvent) -> Error {
         let key = self.xwrap.keycode_to_keysym(event.keycode);
         let mask = xkeysym_lookup::clean_mask(event.state);
+        self.execute_keybind(mask, key)
+    }
+
+    /// Executes the command of the keybind matching the mask and key, taking the current chord
+    /// into account.
+    /// # Errors
+    ///
+    /// Will error if no keybind matches or its command fails.
+    pub fn execute_keybind(
+        &mut self,
+        mask: xkeysym_lookup::ModMask,
+        key: xkeysym_lookup::XKeysym,
+    ) -> Error {
         if let Some(keybind) = self.get_keybind((mask, key)) {
+            match &keybind.name {
+                Some(name) => tracing::debug!("Running keybind {} ({})", keybind, name),
+                None => tracing::debug!("Running keybind {}", keybind),
+            }
+            let mut path = self.chord_ctx.path.clone();
+            path.push(keybind.to_string());
+            self.binding_ctx = context::Binding {
+                keybind: Some(keybind.clone()),
+                path,
+            };
             if let Ok(command) = command::denormalize(&keybind.command) {
                 return command.execute(self);
             }
@@ -99,6 +178,23 @@ impl Worker {
         Ok(())
     }
 
+    /// The path to a file in the lefthk directory of `XDG_STATE_HOME`, creating the directories
+    /// leading to it.
+    /// # Errors
+    ///
+    /// Will error if the directories can't be created.
+    pub fn place_state_file(&self, path: &str) -> errors::Result<PathBuf> {
+        Ok(self.base_directory.place_state_file(path)?)
+    }
+
+    /// The top level keybind with the given name.
+    #[must_use]
+    pub fn named_keybind(&self, name: &str) -> Option<&Keybind> {
+        self.keybinds
+            .iter()
+            .find(|keybind| keybind.name.as_deref() == Some(name))
+    }
+
     fn get_keybind(&self, mask_key_pair: (u32, u32)) -> Option<Keybind> {
         let keybinds = if let Some(keybinds) = &self.chord_ctx.keybinds {
             keybinds
@@ -109,8 +205,9 @@ impl Worker {
             .iter()
             .find(|keybind| {
                 if let Some(key) = xkeysym_lookup::into_keysym(&keybind.key) {
-                    let mask = xkeysym_lookup::into_modmask(&keybind.modifier);
-                    return mask_key_pair == (mask, key);
+                    if let Ok(mask) = xkeysym_lookup::try_into_modmask(&keybind.modifier) {
+                        return mask_key_pair == (mask, key);
+                    }
                 }
                 false
             })
@@ -6,8 +6,9 @@ use std::os::raw::{c_int, c_ulong};
 use std::pin::Pin;
 use std::ptr;
 use std::sync::Arc;
+use tokio::io::Interest;
+use tokio::io::unix::AsyncFd;
 use tokio::sync::{Notify, oneshot};
-use tokio::time::Duration;
 use x11_dl::xlib;
 
 pub struct XWrap {
@@ -31,38 +32,31 @@ impl XWrap {
     #[must_use]
     #[allow(clippy::items_after_statements)]
     pub fn new() -> Self {
-        const SERVER: mio::Token = mio::Token(0);
         let xlib = errors::exit_on_error!(xlib::Xlib::open());
         let display = unsafe { (xlib.XOpenDisplay)(ptr::null()) };
         assert!(!display.is_null(), "Null pointer in display");
 
         let fd = unsafe { (xlib.XConnectionNumber)(display) };
-        let (guard, task_guard) = oneshot::channel();
+        let (mut guard, task_guard) = oneshot::channel();
         let notify = Arc::new(Notify::new());
         let task_notify = notify.clone();
-        let mut poll = errors::exit_on_error!(mio::Poll::new());
-        let mut events = mio::Events::with_capacity(1);
-        errors::exit_on_error!(poll.registry().register(
-            &mut mio::unix::SourceFd(&fd),
-            SERVER,
-            mio::Interest::READABLE,
-        ));
-        let timeout = Duration::from_millis(100);
-        tokio::task::spawn_blocking(move || {
+        // The display owns the fd, it is closed by `XCloseDisplay`.
+        let connection = errors::exit_on_error!(AsyncFd::with_interest(fd, Interest::READABLE));
+        tokio::spawn(async move {
             loop {
-                if guard.is_closed() {
-                    return;
+                tokio::select! {
+                    () = guard.closed() => return,
+                    ready = connection.readable() => match ready {
+                        Ok(mut ready) => {
+                            notify.notify_one();
+                            ready.clear_ready();
+                        }
+                        Err(err) => {
+                            tracing::warn!("Xlib socket poll failed with {:?}", err);
+                            return;
+                        }
+                    },
                 }
-
-                if let Err(err) = poll.poll(&mut events, Some(timeout)) {
-                    tracing::warn!("Xlib socket poll failed with {:?}", err);
-                    continue;
-                }
-
-                events
-                    .iter()
-                    .filter(|event| SERVER == event.token())
-                    .for_each(|_| notify.notify_one());
             }
         });
         let root = unsafe { (xlib.XDefaultRootWindow)(display) };
@@ -120,9 +114,13 @@ impl XWrap {
 
         // Grab all the key combos from the config file.
         for kb in keybinds {
-            if let Some(keysym) = xkeysym_lookup::into_keysym(&kb.key) {
-                let modmask = xkeysym_lookup::into_modmask(&kb.modifier);
+            let grab = xkeysym_lookup::try_into_keysym(&kb.key).and_then(|keysym| {
+                let modmask = xkeysym_lookup::try_into_modmask(&kb.modifier)?;
                 self.grab_key(self.root, keysym, modmask);
+                Ok(())
+            });
+            if let Err(err) = grab {
+                tracing::error!("Skipping keybind {}: {}", kb, err);
             }
         }
     }
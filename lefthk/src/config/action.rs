@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use crate::{
+    config::{command::Command, keybind::Keybinds},
+    errors::{LeftError, Result},
+};
+
+/// Replaces every `Action("name")` with the command defined for it in `actions`, including the
+/// ones nested in chords, in other actions and in `on_failure`.
+pub(crate) fn resolve(keybinds: Keybinds, actions: &HashMap<String, Command>) -> Result<Keybinds> {
+    resolve_keybinds(keybinds, actions, &mut Vec::new())
+}
+
+fn resolve_keybinds(
+    keybinds: Keybinds,
+    actions: &HashMap<String, Command>,
+    stack: &mut Vec<String>,
+) -> Result<Keybinds> {
+    keybinds
+        .into_iter()
+        .map(|mut kb| {
+            kb.command = resolve_command(kb.command, actions, stack)?;
+            if let Some(on_failure) = kb.on_failure {
+                kb.on_failure = Some(Box::new(resolve_command(*on_failure, actions, stack)?));
+            }
+            Ok(kb)
+        })
+        .collect()
+}
+
+fn resolve_command(
+    command: Command,
+    actions: &HashMap<String, Command>,
+    stack: &mut Vec<String>,
+) -> Result<Command> {
+    match command {
+        Command::Action(name) => {
+            if stack.contains(&name) {
+                return Err(LeftError::ActionCycle(name));
+            }
+            let action = actions
+                .get(&name)
+                .cloned()
+                .ok_or_else(|| LeftError::ActionNotFound(name.clone()))?;
+            stack.push(name);
+            let resolved = resolve_command(action, actions, stack)?;
+            stack.pop();
+            Ok(resolved)
+        }
+        Command::Chord(children) => Ok(Command::Chord(resolve_keybinds(children, actions, stack)?)),
+        command => Ok(command),
+    }
+}
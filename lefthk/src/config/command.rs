@@ -1,13 +1,145 @@
-use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt, marker::PhantomData};
 
-use crate::config::keybind::Keybind;
+use lefthk_core::config::command::utils::process::Process;
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{self, MapAccess, SeqAccess, Visitor},
+};
+
+use crate::{
+    config::{keybind::Keybind, variable},
+    errors::Result,
+};
 
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
 pub enum Command {
+    /// Refers to one of the `actions` of the config by name, e.g. `Action("term")`.
+    Action(String),
     Chord(Vec<Keybind>),
-    Execute(String),
-    Executes(Vec<String>),
+    Execute(Execute),
+    Executes(Execute<Vec<String>>),
+    /// Runs a program with its arguments without a shell, e.g. `Spawn(["st", "-e", "htop"])`.
+    Spawn(Execute<Vec<String>>),
     ExitChord,
     Reload,
     Kill,
 }
+
+/// The command of an `Execute`, `Executes` or `Spawn`, either on its own or with settings for its
+/// process, e.g. `Execute(cmd: "make", cwd: "~/proj", env: {"RUST_LOG": "debug"}, clear_env: true)`.
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+#[serde(untagged)]
+pub enum Execute<T = String> {
+    Command(T),
+    WithProcess {
+        cmd: T,
+        env: HashMap<String, String>,
+        clear_env: bool,
+        cwd: Option<String>,
+    },
+}
+
+/// The form with settings, which rejects unknown fields by their name.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WithProcess<T> {
+    cmd: T,
+    #[serde(default)]
+    env: HashMap<String, String>,
+    #[serde(default)]
+    clear_env: bool,
+    #[serde(default, deserialize_with = "super::some")]
+    cwd: Option<String>,
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Execute<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(ExecuteVisitor(PhantomData))
+    }
+}
+
+struct ExecuteVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ExecuteVisitor<T> {
+    type Value = Execute<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a command, or `cmd` with settings for its process")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> std::result::Result<Self::Value, E> {
+        T::deserialize(de::value::StrDeserializer::new(v)).map(Execute::Command)
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> std::result::Result<Self::Value, E> {
+        T::deserialize(de::value::StringDeserializer::new(v)).map(Execute::Command)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> std::result::Result<Self::Value, A::Error> {
+        T::deserialize(de::value::SeqAccessDeserializer::new(seq)).map(Execute::Command)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> std::result::Result<Self::Value, A::Error> {
+        let WithProcess {
+            cmd,
+            env,
+            clear_env,
+            cwd,
+        } = WithProcess::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        Ok(Execute::WithProcess {
+            cmd,
+            env,
+            clear_env,
+            cwd,
+        })
+    }
+}
+
+impl<T: Clone> Execute<T> {
+    #[must_use]
+    pub fn cmd(&self) -> &T {
+        match self {
+            Self::Command(cmd) | Self::WithProcess { cmd, .. } => cmd,
+        }
+    }
+
+    /// The same settings with another command.
+    #[must_use]
+    pub fn with_cmd(&self, cmd: T) -> Self {
+        let mut execute = self.clone();
+        match &mut execute {
+            Self::Command(old) | Self::WithProcess { cmd: old, .. } => *old = cmd,
+        }
+        execute
+    }
+
+    /// The settings for the process on top of the given ones, with variables expanded in the
+    /// values of `env` and `cwd`.
+    pub(crate) fn process(
+        &self,
+        process: Process,
+        vars: &HashMap<String, String>,
+    ) -> Result<Process> {
+        let Self::WithProcess {
+            env,
+            clear_env,
+            cwd,
+            ..
+        } = self
+        else {
+            return Ok(process);
+        };
+        Ok(Process {
+            env: env
+                .iter()
+                .map(|(name, value)| Ok((name.clone(), variable::expand(value, vars)?)))
+                .collect::<Result<_>>()?,
+            clear_env: *clear_env,
+            cwd: cwd
+                .as_deref()
+                .map(|cwd| variable::expand(cwd, vars))
+                .transpose()?,
+            ..process
+        })
+    }
+}
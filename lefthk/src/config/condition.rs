@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{command::Command, keybind::Keybinds};
+
+/// Decides whether a keybind or config file is used on this machine, e.g.
+/// `when: Any([Hostname("laptop"), Display(":1")])`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum Condition {
+    Hostname(String),
+    /// An environment variable with the given value, e.g. `Env("XDG_SESSION_DESKTOP", "leftwm")`.
+    Env(String, String),
+    /// The X display lefthk runs on, e.g. `Display(":1")`.
+    Display(String),
+    Any(Vec<Condition>),
+    All(Vec<Condition>),
+    Not(Box<Condition>),
+}
+
+impl Condition {
+    #[must_use]
+    pub fn holds(&self) -> bool {
+        match self {
+            Self::Hostname(name) => nix::unistd::gethostname()
+                .is_ok_and(|hostname| hostname.to_string_lossy() == name.as_str()),
+            Self::Env(name, value) => std::env::var(name).is_ok_and(|var| var == *value),
+            Self::Display(display) => std::env::var("DISPLAY").is_ok_and(|var| var == *display),
+            Self::Any(conditions) => conditions.iter().any(Self::holds),
+            Self::All(conditions) => conditions.iter().all(Self::holds),
+            Self::Not(condition) => !condition.holds(),
+        }
+    }
+}
+
+/// Drops the keybinds whose condition doesn't hold, including the ones nested in chords. Chords
+/// left without keybinds are dropped as well.
+pub(crate) fn filter(keybinds: Keybinds) -> Keybinds {
+    keybinds
+        .into_iter()
+        .filter(|kb| kb.when.as_ref().is_none_or(Condition::holds))
+        .filter_map(|mut kb| {
+            if let Command::Chord(children) = &mut kb.command {
+                let had_children = !children.is_empty();
+                *children = filter(std::mem::take(children));
+                if had_children && children.is_empty() {
+                    return None;
+                }
+            }
+            Some(kb)
+        })
+        .collect()
+}
@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use ron::extensions::Extensions;
+use serde::de::DeserializeOwned;
+
+use crate::errors::Result;
+
+/// The formats a config file can be written in, picked by the file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Ron,
+    Toml,
+    Yaml,
+    Json,
+}
+
+impl Format {
+    /// The extensions a config file is looked up with, in order of preference.
+    pub const EXTENSIONS: [&str; 5] = ["ron", "toml", "yaml", "yml", "json"];
+
+    /// Files with an unknown or missing extension are read as RON.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::Toml,
+            Some("yaml" | "yml") => Self::Yaml,
+            Some("json") => Self::Json,
+            _ => Self::Ron,
+        }
+    }
+
+    /// # Errors
+    ///
+    /// This errors, when the contents are not valid in this format
+    pub fn parse<T: DeserializeOwned>(self, contents: &str) -> Result<T> {
+        Ok(match self {
+            // Lets commands with settings be written as `Execute(cmd: "make", cwd: "~/proj")`.
+            Self::Ron => ron::Options::default()
+                .with_default_extension(Extensions::UNWRAP_VARIANT_NEWTYPES)
+                .from_str(contents)?,
+            Self::Toml => toml::from_str(contents)?,
+            Self::Yaml => serde_yaml_ng::from_str(contents)?,
+            Self::Json => serde_json::from_str(contents)?,
+        })
+    }
+}
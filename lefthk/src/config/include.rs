@@ -0,0 +1,160 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{self, MapAccess, Visitor},
+};
+
+use crate::{
+    config::{Config, command::Command, condition::Condition, key::Key, keybind::Keybinds},
+    errors::{LeftError, Result},
+};
+
+/// An entry of `include`, either a path or one that is only included where its condition holds,
+/// e.g. `Include(path: "laptop.ron", when: Hostname("laptop"))`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self", rename = "Include", deny_unknown_fields)]
+pub struct Include {
+    path: String,
+    #[serde(
+        default,
+        deserialize_with = "super::some",
+        skip_serializing_if = "Option::is_none"
+    )]
+    when: Option<Condition>,
+}
+
+impl Serialize for Include {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match &self.when {
+            None => self.path.serialize(serializer),
+            Some(_) => Self::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Include {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(IncludeVisitor)
+    }
+}
+
+struct IncludeVisitor;
+
+impl<'de> Visitor<'de> for IncludeVisitor {
+    type Value = Include;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a path, or `Include` with a path and a condition")
+    }
+
+    fn visit_str<E: de::Error>(self, path: &str) -> std::result::Result<Include, E> {
+        Ok(Include {
+            path: path.to_owned(),
+            when: None,
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> std::result::Result<Include, A::Error> {
+        Include::deserialize(de::value::MapAccessDeserializer::new(map))
+    }
+}
+
+/// Loads a config file and appends the keybinds of every file it includes, in order.
+///
+/// Includes are resolved relative to the including file and may contain `~` and glob patterns.
+/// Included files can include further files, as long as no file ends up including itself.
+pub(crate) fn load(file: &Path) -> Result<Config> {
+    load_file(file, &mut Vec::new())
+}
+
+fn load_file(file: &Path, stack: &mut Vec<PathBuf>) -> Result<Config> {
+    let canonical =
+        fs::canonicalize(file).map_err(|_| LeftError::IncludeNotFound(file.to_owned()))?;
+    if stack.contains(&canonical) {
+        return Err(LeftError::IncludeCycle(file.to_owned()));
+    }
+    let contents = fs::read_to_string(file)?;
+    let mut config = Config::parse(&contents, Some(file)).map_err(|err| {
+        // Errors in the main config are reported as is, without repeating its path.
+        if stack.is_empty() {
+            err
+        } else {
+            LeftError::InFile(file.to_owned(), Box::new(err))
+        }
+    })?;
+
+    if !stack.is_empty() && !config.default_modifier.is_empty() {
+        apply_default_modifier(&mut config.keybinds, &config.default_modifier);
+    }
+
+    config.files.push(file.to_owned());
+    stack.push(canonical);
+    let directory = file.parent().unwrap_or_else(|| Path::new("."));
+    for include in &config.include {
+        if !include.when.as_ref().is_none_or(Condition::holds) {
+            continue;
+        }
+        for included in resolve(&include.path, directory)? {
+            let included = load_file(&included, stack)?;
+            config.files.extend(included.files);
+            config.keybinds.extend(included.keybinds);
+            for (name, action) in included.actions {
+                config.actions.entry(name).or_insert(action);
+            }
+            for (name, value) in included.vars {
+                config.vars.entry(name).or_insert(value);
+            }
+        }
+    }
+    stack.pop();
+
+    Ok(config)
+}
+
+/// Turns the plain keys of an included file into combos with its own `default_modifier`, so they
+/// don't get the one of the including file.
+fn apply_default_modifier(keybinds: &mut Keybinds, default_modifier: &[String]) {
+    let add_modifier = |key: &mut String| {
+        if !key.contains('+') {
+            *key = format!("{}+{key}", default_modifier.join("+"));
+        }
+    };
+    for kb in keybinds {
+        if kb.modifier.is_none() {
+            match &mut kb.key {
+                Key::Key(key) => add_modifier(key),
+                Key::Keys(keys) => keys.iter_mut().for_each(add_modifier),
+            }
+        }
+        if let Command::Chord(children) = &mut kb.command {
+            apply_default_modifier(children, default_modifier);
+        }
+    }
+}
+
+fn resolve(pattern: &str, directory: &Path) -> Result<Vec<PathBuf>> {
+    let path = directory.join(expand_home(pattern));
+    if !pattern.contains(['*', '?', '[']) {
+        return Ok(vec![path]);
+    }
+    let paths = glob::glob(&path.to_string_lossy())
+        .map_err(|_| LeftError::InvalidInclude(pattern.to_owned()))?;
+    Ok(paths.filter_map(std::result::Result::ok).collect())
+}
+
+/// Replaces a leading `~` with the home directory.
+pub(crate) fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix('~'), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+            PathBuf::from(home).join(rest.trim_start_matches('/'))
+        }
+        _ => PathBuf::from(path),
+    }
+}
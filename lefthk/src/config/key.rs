@@ -5,3 +5,10 @@ pub enum Key {
     Key(String),
     Keys(Vec<String>),
 }
+
+/// No key at all, used when a keybind sets a `combo` instead.
+impl Default for Key {
+    fn default() -> Self {
+        Self::Keys(Vec::new())
+    }
+}
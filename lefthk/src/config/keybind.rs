@@ -1,10 +1,23 @@
 use crate::errors::{LeftError, Result};
-use lefthk_core::config::{
-    Command as core_command, Keybind as core_keybind, command as command_mod,
+use lefthk_core::{
+    config::{
+        Command as core_command, Keybind as core_keybind,
+        command::{
+            self as command_mod,
+            utils::{
+                normalized_command::NormalizedCommand,
+                output::Output,
+                process::{Instance, OnExit, Process},
+            },
+        },
+        split_combo,
+    },
+    xkeysym_lookup,
 };
 use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt, path::PathBuf};
 
-use crate::config::{command::Command, key::Key};
+use crate::config::{command::Command, condition::Condition, key::Key, template, variable};
 
 // TODO: Replace with expr_2024
 macro_rules! get_key {
@@ -32,16 +45,127 @@ pub type Keybinds = Vec<Keybind>;
 pub struct Keybind {
     pub command: Command,
     pub modifier: Option<Vec<String>>,
+    #[serde(default)]
     pub key: Key,
+    /// Alternative to `key`, e.g. `combo: "Super+Shift+Return"`.
+    pub combo: Option<String>,
+    /// Keys to press one after another, e.g. `sequence: "Super+a w t"`. Expanded into chords.
+    pub sequence: Option<String>,
+    /// A stable name to refer to the keybind, e.g. `name: "terminal"`.
+    pub name: Option<String>,
+    /// What the keybind does, e.g. `description: "Open terminal"`.
+    pub description: Option<String>,
+    /// Only use the keybind where this holds, e.g. `when: Hostname("laptop")`.
+    pub when: Option<Condition>,
+    /// Where the output of the commands goes, e.g. `output: Log`. Defaults to the one of the
+    /// config.
+    pub output: Option<Output>,
+    /// Runs when a command of the keybind exits unsuccessfully, e.g.
+    /// `on_failure: Execute("notify-send 'Build failed'")`.
+    pub on_failure: Option<Box<Command>>,
+    /// What pressing the keybind again does while its command still runs: `Single`, `Toggle` or
+    /// `Restart`. Starts another one by default.
+    pub instance: Option<Instance>,
+    /// What happens to the started processes when lefthk quits or reloads, e.g.
+    /// `on_exit: Kill(500)`. Defaults to the one of the config.
+    pub on_exit: Option<OnExit>,
+    #[serde(skip)]
+    pub origin: Option<Origin>,
 }
 
-pub(crate) fn try_from(kb: Keybind, default_modifier: &[String]) -> Result<Vec<core_keybind>> {
+/// Where a keybind was defined, used to point at it in error messages.
+#[derive(Debug, PartialEq, Clone, Eq)]
+pub struct Origin {
+    pub file: PathBuf,
+    pub index: usize,
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: keybinds[{}]", self.file.display(), self.index)
+    }
+}
+
+/// The parts of a config that keybinds are converted with.
+pub(crate) struct Settings<'a> {
+    pub default_modifier: &'a [String],
+    pub vars: &'a HashMap<String, String>,
+    /// The shell string commands are run with, `sh -c` if empty.
+    pub shell: &'a [String],
+    pub output: &'a Output,
+    pub on_exit: OnExit,
+}
+
+impl Settings<'_> {
+    /// A string command, run with the configured shell if there is one.
+    fn execute(&self, command: &str, process: Process) -> Result<Box<dyn core_command>> {
+        let command = variable::expand(command, self.vars)?;
+        if self.shell.is_empty() {
+            return Ok(Box::new(
+                command_mod::Execute::new(&command).with_process(process),
+            ));
+        }
+        let mut argv = self.shell.to_vec();
+        argv.push(command);
+        Ok(Box::new(
+            command_mod::Spawn::new(&argv).with_process(process),
+        ))
+    }
+
+    /// A program with its arguments, run without a shell.
+    fn spawn(&self, argv: &[String], process: Process) -> Result<Box<dyn core_command>> {
+        let argv = argv
+            .iter()
+            .map(|arg| variable::expand(arg, self.vars))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Box::new(
+            command_mod::Spawn::new(&argv).with_process(process),
+        ))
+    }
+}
+
+/// The command to run when a command of the keybind fails, which runs like the ones of the
+/// keybind itself.
+fn on_failure(
+    command: &Command,
+    process: Process,
+    settings: &Settings,
+) -> Result<NormalizedCommand> {
+    let command = match command {
+        Command::Execute(execute) if !execute.cmd().is_empty() => {
+            settings.execute(execute.cmd(), execute.process(process, settings.vars)?)?
+        }
+        Command::Spawn(spawn) if !spawn.cmd().is_empty() => {
+            settings.spawn(spawn.cmd(), spawn.process(process, settings.vars)?)?
+        }
+        Command::Reload => Box::new(command_mod::Reload::new()),
+        Command::Kill => Box::new(command_mod::Kill::new()),
+        _ => return Err(LeftError::InvalidOnFailure),
+    };
+    Ok(command.normalize())
+}
+
+pub(crate) fn try_from(kb: Keybind, settings: &Settings) -> Result<Vec<core_keybind>> {
+    let key = key(&kb)?;
+    let output = match kb.output.clone().unwrap_or_else(|| settings.output.clone()) {
+        Output::File(path) => Output::File(variable::expand(&path, settings.vars)?),
+        output => output,
+    };
+    let mut process = Process {
+        output,
+        on_exit: kb.on_exit.unwrap_or(settings.on_exit),
+        ..Process::default()
+    };
+    if let Some(command) = &kb.on_failure {
+        process.on_failure = Some(on_failure(command, process.clone(), settings)?);
+    }
+    process.instance = kb.instance.unwrap_or_default();
     let command_key_pairs: Vec<(Box<dyn core_command>, String)> = match kb.command {
         Command::Chord(children) if !children.is_empty() => {
-            let key = get_key!(kb.key);
+            let key = get_key!(key);
             let children = children
                 .iter()
-                .filter_map(|kb| match try_from(kb.clone(), default_modifier) {
+                .filter_map(|kb| match try_from(kb.clone(), settings) {
                     Ok(keybinds) => Some::<Vec<lefthk_core::config::Keybind>>(keybinds),
                     Err(err) => {
                         tracing::error!("Invalid key binding: {}\n{:?}", err, kb);
@@ -54,51 +178,104 @@ pub(crate) fn try_from(kb: Keybind, default_modifier: &[String]) -> Result<Vec<c
             vec![(Box::new(command_mod::Chord::new(children)), key)]
         }
         Command::Chord(_) => return Err(LeftError::ChildrenNotFound),
-        Command::Execute(value) if !value.is_empty() => {
-            let keys = get_key!(kb.key);
-            vec![((Box::new(command_mod::Execute::new(&value))), keys)]
+        // Actions are resolved when loading the config, this one wasn't defined.
+        Command::Action(name) => return Err(LeftError::ActionNotFound(name)),
+        Command::Execute(execute) if !execute.cmd().is_empty() => {
+            let keys = get_key!(key);
+            let process = execute.process(process, settings.vars)?;
+            vec![(settings.execute(execute.cmd(), process)?, keys)]
         }
         Command::Execute(_) => return Err(LeftError::ValueNotFound),
-        Command::Executes(values) if !values.is_empty() => {
-            let keys = get_keys!(kb.key);
+        Command::Executes(executes) if !executes.cmd().is_empty() => {
+            let keys = get_keys!(key);
+            let values = executes.cmd();
+            let process = executes.process(process, settings.vars)?;
             if keys.len() != values.len() {
                 return Err(LeftError::NumberOfKeysDiffersFromValues);
             }
             values
                 .iter()
                 .enumerate()
-                .map(|(i, v)| {
-                    (
-                        Box::new(command_mod::Execute::new(&v)) as Box<dyn core_command>,
-                        keys[i].clone(),
-                    )
-                })
-                .collect()
+                .map(|(i, v)| Ok((settings.execute(v, process.clone())?, keys[i].clone())))
+                .collect::<Result<_>>()?
         }
         Command::Executes(_) => return Err(LeftError::ValuesNotFound),
+        Command::Spawn(spawn) if !spawn.cmd().is_empty() => {
+            let keys = get_key!(key);
+            let process = spawn.process(process, settings.vars)?;
+            vec![(settings.spawn(spawn.cmd(), process)?, keys)]
+        }
+        Command::Spawn(_) => return Err(LeftError::ProgramNotFound),
         Command::ExitChord => {
-            let keys = get_key!(kb.key);
+            let keys = get_key!(key);
             vec![((Box::new(command_mod::ExitChord::new())), keys)]
         }
         Command::Reload => {
-            let keys = get_key!(kb.key);
+            let keys = get_key!(key);
             vec![((Box::new(command_mod::Reload::new())), keys)]
         }
         Command::Kill => {
-            let keys = get_key!(kb.key);
+            let keys = get_key!(key);
             vec![((Box::new(command_mod::Kill::new())), keys)]
         }
     };
-    let keybinds = command_key_pairs
+    command_key_pairs
         .iter()
-        .map(|(c, k)| core_keybind {
-            command: c.normalize(),
-            modifier: kb
-                .modifier
-                .clone()
-                .unwrap_or_else(|| default_modifier.to_vec()),
-            key: k.clone(),
+        .map(|(c, k)| {
+            let (modifier, key) = split_key(k, kb.modifier.as_deref(), settings.default_modifier)?;
+            check_names(&key, &modifier)?;
+            Ok(core_keybind {
+                command: c.normalize(),
+                modifier,
+                key,
+                name: kb.name.clone(),
+                description: kb.description.clone(),
+            })
         })
-        .collect();
-    Ok(keybinds)
+        .collect()
+}
+
+/// The key of a keybind, taken from either the `key` or the `combo` field.
+pub(crate) fn key(kb: &Keybind) -> Result<Key> {
+    // Keybinds with invalid templates are left unexpanded when loading the config.
+    template::check(kb)?;
+    if kb.sequence.is_some() {
+        // Sequences are expanded when loading the config, this one also had a modifier, key or
+        // combo set.
+        if kb.modifier.is_some() {
+            return Err(LeftError::ModifiersSetTwice);
+        }
+        return Err(LeftError::KeyOrComboNeeded);
+    }
+    match (&kb.key, &kb.combo) {
+        (Key::Keys(keys), None) if keys.is_empty() => Err(LeftError::KeyOrComboNeeded),
+        (key, None) => Ok(key.clone()),
+        (Key::Keys(keys), Some(combo)) if keys.is_empty() => Ok(Key::Key(combo.clone())),
+        (_, Some(_)) => Err(LeftError::KeyOrComboNeeded),
+    }
+}
+
+/// Splits a key that may be a combo like `Super+Shift+Return` into its modifiers and key. Plain
+/// keys use the modifiers of the keybind, or the default modifiers if it has none.
+pub(crate) fn split_key(
+    key: &str,
+    modifier: Option<&[String]>,
+    default_modifier: &[String],
+) -> Result<(Vec<String>, String)> {
+    if !key.contains('+') {
+        let modifier = modifier.unwrap_or(default_modifier).to_vec();
+        return Ok((modifier, key.to_owned()));
+    }
+    if modifier.is_some() {
+        return Err(LeftError::ModifiersSetTwice);
+    }
+    split_combo(key).ok_or_else(|| LeftError::InvalidCombo(key.to_owned()))
+}
+
+/// Makes sure the key and all modifiers are known, so a typo doesn't silently drop the keybind or
+/// turn it into one without modifiers.
+pub(crate) fn check_names(key: &str, modifier: &[String]) -> Result<()> {
+    xkeysym_lookup::try_into_keysym(key)?;
+    xkeysym_lookup::try_into_modmask(modifier)?;
+    Ok(())
 }
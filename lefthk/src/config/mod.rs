@@ -1,78 +1,390 @@
+mod action;
 pub mod command;
+pub mod condition;
+pub mod format;
+mod include;
 pub mod key;
 pub mod keybind;
+mod sequence;
+pub(crate) mod template;
+mod variable;
 
 use crate::errors::{LeftError, Result};
 
-use serde::{Deserialize, Serialize};
-use std::{fs, path::Path};
+use lefthk_core::{
+    config::{
+        command::utils::{output::Output, process::OnExit},
+        split_combo,
+    },
+    xkeysym_lookup::{self, ModMask, XKeysym},
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
 use xdg::BaseDirectories;
 
 use self::{
     command::Command,
+    condition::Condition,
+    format::Format,
+    include::Include,
+    key::Key,
     keybind::{Keybind, Keybinds},
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
 pub struct Config {
+    /// Only use this config file where this holds, e.g. `when: Hostname("laptop")`.
+    #[serde(
+        default,
+        deserialize_with = "some",
+        skip_serializing_if = "Option::is_none"
+    )]
+    when: Option<Condition>,
+    #[serde(default)]
     pub(crate) default_modifier: Vec<String>,
+    /// The shell to run string commands with, e.g. `["bash", "-lc"]`. Defaults to `sh -c`.
+    #[serde(default)]
+    shell: Vec<String>,
+    /// Where the output of commands goes unless their keybind says otherwise: `Null`, `Inherit`,
+    /// `Log` or `File("name.log")` in `$XDG_STATE_HOME/lefthk/`.
+    #[serde(default)]
+    output: Output,
+    /// What happens to started processes when lefthk quits or reloads unless their keybind says
+    /// otherwise: `Detach`, `Terminate` or `Kill(grace_ms)`.
+    #[serde(default)]
+    on_exit: OnExit,
+    /// Further config files whose keybinds are added after these, e.g. `["media.ron"]`.
+    #[serde(default)]
+    include: Vec<Include>,
+    /// Combos of inherited system wide keybinds to drop, e.g. `["Mod4+Return"]`.
+    #[serde(default)]
+    disable: Vec<String>,
+    /// Commands that keybinds can refer to by name, e.g. `{"term": Execute("alacritty")}`.
+    #[serde(default)]
+    actions: HashMap<String, Command>,
+    /// Values for `${name}` in commands, e.g. `{"term": "alacritty"}`. These also apply to the
+    /// system wide configs below, overriding their own.
+    #[serde(default)]
+    vars: HashMap<String, String>,
     keybinds: Keybinds,
+    /// The system wide config this one is layered on top of.
+    #[serde(skip)]
+    inherited: Option<Box<Config>>,
+    /// The file this config was read from and the ones it included.
+    #[serde(skip)]
+    files: Vec<PathBuf>,
 }
 
 impl lefthk_core::config::Config for Config {
     fn mapped_bindings(&self) -> Vec<lefthk_core::config::Keybind> {
-        self.keybinds
+        let settings = self.settings();
+        let mut bindings: Vec<lefthk_core::config::Keybind> = self
+            .keybinds
             .iter()
-            .filter_map(
-                |kb| match keybind::try_from(kb.clone(), &self.default_modifier.clone()) {
-                    Ok(keybinds) => Some::<Vec<lefthk_core::config::Keybind>>(keybinds),
+            .filter_map(|kb| match keybind::try_from(kb.clone(), &settings) {
+                Ok(keybinds) => Some::<Vec<lefthk_core::config::Keybind>>(keybinds),
+                Err(err) => {
+                    match &kb.origin {
+                        Some(origin) => {
+                            tracing::error!("Invalid key binding at {}: {}", origin, err);
+                        }
+                        None => tracing::error!("Invalid key binding: {}\n{:?}", err, kb),
+                    }
+                    None
+                }
+            })
+            .flatten()
+            .collect();
+        if let Some(inherited) = &self.inherited {
+            let disabled: Vec<_> = self
+                .disable
+                .iter()
+                .filter_map(|combo| match disabled_combo(combo) {
+                    Ok(combo) => Some(combo),
                     Err(err) => {
-                        tracing::error!("Invalid key binding: {}\n{:?}", err, kb);
+                        tracing::error!("Invalid combo to disable: {}", err);
                         None
                     }
-                },
-            )
-            .flatten()
-            .collect()
+                })
+                .collect();
+            bindings.extend(inherited.mapped_bindings().into_iter().filter(|kb| {
+                let combo = (
+                    xkeysym_lookup::into_modmask(&kb.modifier),
+                    xkeysym_lookup::into_keysym(&kb.key),
+                );
+                !disabled.contains(&combo)
+            }));
+        }
+        bindings
     }
 }
 
-impl TryFrom<String> for Config {
-    type Error = LeftError;
-    /// # Errors
-    ///
-    /// Thes will error when no config file is found, most propably as system or
-    /// user error for provideng a wrong path
-    fn try_from(contents: String) -> Result<Self> {
-        let mut config: Config = ron::from_str(&contents)?;
-        let global_exit_chord = config
+/// Turns a combo like `Mod4+Return` into the modifier mask and keysym it is grabbed with.
+fn disabled_combo(combo: &str) -> Result<(ModMask, Option<XKeysym>)> {
+    let (modifier, key) =
+        split_combo(combo).ok_or_else(|| LeftError::InvalidCombo(combo.to_owned()))?;
+    keybind::check_names(&key, &modifier)?;
+    Ok((
+        xkeysym_lookup::into_modmask(&modifier),
+        xkeysym_lookup::into_keysym(&key),
+    ))
+}
+
+/// A problem found by [`Config::check`], together with the keybind it was found in.
+#[derive(Debug)]
+pub struct Problem {
+    pub location: String,
+    pub error: LeftError,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.error)
+    }
+}
+
+impl Config {
+    /// Checks every keybind, including the ones nested in chords, and collects all problems
+    /// instead of dropping the invalid keybinds like `mapped_bindings` does.
+    #[must_use]
+    pub fn check(&self) -> Vec<Problem> {
+        let settings = self.settings();
+        let mut problems = Vec::new();
+        for (i, kb) in self.keybinds.iter().enumerate() {
+            let location = kb
+                .origin
+                .as_ref()
+                .map_or_else(|| format!("keybinds[{i}]"), ToString::to_string);
+            check_keybind(kb, &settings, &location, &mut problems);
+        }
+        for (i, combo) in self.disable.iter().enumerate() {
+            if let Err(error) = disabled_combo(combo) {
+                problems.push(Problem {
+                    location: format!("disable[{i}]"),
+                    error,
+                });
+            }
+        }
+        if let Some(inherited) = &self.inherited {
+            problems.extend(inherited.check());
+        }
+        problems
+    }
+
+    /// Every file this config was loaded from, including the system wide configs below it.
+    #[must_use]
+    pub fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.files.clone();
+        if let Some(inherited) = &self.inherited {
+            files.extend(inherited.files());
+        }
+        files
+    }
+
+    fn settings(&self) -> keybind::Settings<'_> {
+        keybind::Settings {
+            default_modifier: &self.default_modifier,
+            vars: &self.vars,
+            shell: &self.shell,
+            output: &self.output,
+            on_exit: self.on_exit,
+        }
+    }
+
+    /// Hands the variables of a config layered on top down to this one and the ones below it.
+    fn override_vars(&mut self, vars: &HashMap<String, String>) {
+        self.vars.extend(vars.clone());
+        if let Some(inherited) = &mut self.inherited {
+            inherited.override_vars(vars);
+        }
+    }
+
+    /// Parses a single config file in the format given by its extension, without following its
+    /// includes.
+    fn parse(contents: &str, file: Option<&Path>) -> Result<Self> {
+        let format = file.map(Format::from_path).unwrap_or_default();
+        let mut config: Config = format.parse(contents)?;
+        if let Some(file) = file {
+            for (index, kb) in config.keybinds.iter_mut().enumerate() {
+                kb.origin = Some(keybind::Origin {
+                    file: file.to_owned(),
+                    index,
+                });
+            }
+        }
+        // A config that doesn't apply here adds nothing, neither by itself nor by including.
+        if !config.when.as_ref().is_none_or(Condition::holds) {
+            config.include.clear();
+            config.disable.clear();
+            config.actions.clear();
+            config.vars.clear();
+            config.keybinds.clear();
+        }
+        config.keybinds = condition::filter(config.keybinds);
+        config.keybinds = template::expand(config.keybinds);
+        Ok(config)
+    }
+
+    /// Resolves actions, expands sequences and hands exit chords down to the chords, once all
+    /// keybinds are known.
+    fn finish(mut self) -> Result<Self> {
+        self.keybinds = action::resolve(self.keybinds, &self.actions)?;
+        self.keybinds = sequence::expand(self.keybinds, &self.default_modifier)?;
+        let global_exit_chord = self
             .keybinds
             .iter()
             .find(|kb| matches!(kb.command, Command::ExitChord))
             .cloned();
-        let chords: Vec<&mut Keybind> = config
+        let chords: Vec<&mut Keybind> = self
             .keybinds
             .iter_mut()
             .filter(|kb| matches!(kb.command, Command::Chord(_)))
             .collect();
         propagate_exit_chord(chords, &global_exit_chord);
+        Ok(self)
+    }
+}
 
-        Ok(config)
+fn check_keybind(
+    kb: &Keybind,
+    settings: &keybind::Settings,
+    location: &str,
+    problems: &mut Vec<Problem>,
+) {
+    let mut report = |error| {
+        problems.push(Problem {
+            location: location.to_owned(),
+            error,
+        });
+    };
+    match &kb.command {
+        // Converting a chord would also convert (and log) its children, these are checked below.
+        Command::Chord(children) if children.is_empty() => report(LeftError::ChildrenNotFound),
+        Command::Chord(_) => {
+            let checked = keybind::key(kb).and_then(|key| match key {
+                Key::Key(key) => {
+                    let (modifier, key) = keybind::split_key(
+                        &key,
+                        kb.modifier.as_deref(),
+                        settings.default_modifier,
+                    )?;
+                    keybind::check_names(&key, &modifier)
+                }
+                Key::Keys(_) => Err(LeftError::SingleKeyNeeded),
+            });
+            if let Err(err) = checked {
+                report(err);
+            }
+        }
+        _ => {
+            if let Err(err) = keybind::try_from(kb.clone(), settings) {
+                report(err);
+            }
+        }
+    }
+    if let Command::Chord(children) = &kb.command {
+        for (i, child) in children.iter().enumerate() {
+            check_keybind(
+                child,
+                settings,
+                &format!("{location} > Chord[{i}]"),
+                problems,
+            );
+        }
+    }
+}
+
+impl TryFrom<String> for Config {
+    type Error = LeftError;
+    /// # Errors
+    ///
+    /// Thes will error when no config file is found, most propably as system or
+    /// user error for provideng a wrong path
+    fn try_from(contents: String) -> Result<Self> {
+        Config::parse(&contents, None)?.finish()
     }
 }
 
+/// The default config file in the XDG config home, `config.ron` unless there is a config in
+/// another format.
 /// # Errors
 ///
-/// This errors, when no Config is found at the path
-pub fn load() -> Result<Config> {
+/// This errors, when the config directory can't be created
+pub fn default_config_file() -> Result<PathBuf> {
     let path = BaseDirectories::with_prefix(lefthk_core::LEFTHK_DIR_NAME);
-    fs::create_dir_all(path.get_config_home().ok_or(LeftError::ConfigPlaceFailed)?)?;
-    let file_name = path.place_config_file("config.ron")?;
-    if !Path::new(&file_name).exists() {
+    let config_home = path.get_config_home().ok_or(LeftError::ConfigPlaceFailed)?;
+    fs::create_dir_all(&config_home)?;
+    match find_config_file(&config_home) {
+        Some(config_file) => Ok(config_file),
+        None => Ok(path.place_config_file("config.ron")?),
+    }
+}
+
+/// The first `config.<ext>` in the directory, trying the formats in order.
+fn find_config_file(dir: &Path) -> Option<PathBuf> {
+    Format::EXTENSIONS
+        .iter()
+        .map(|ext| dir.join("config").with_extension(ext))
+        .find(|file| file.exists())
+}
+
+/// # Errors
+///
+/// This errors, when no Config is found at the path
+pub fn load(file_name: &Path) -> Result<Config> {
+    if !file_name.exists() {
         return Err(LeftError::NoConfigFound);
     }
-    let contents = fs::read_to_string(file_name)?;
-    Config::try_from(contents)
+    include::load(file_name)?.finish()
+}
+
+/// The system wide config files found in `XDG_CONFIG_DIRS`, most important first.
+#[must_use]
+pub fn system_config_files() -> Vec<PathBuf> {
+    BaseDirectories::with_prefix(lefthk_core::LEFTHK_DIR_NAME)
+        .get_config_dirs()
+        .iter()
+        .filter_map(|dir| find_config_file(dir))
+        .collect()
+}
+
+/// Loads the user config layered on top of the system wide configs, most important first.
+///
+/// Each config keeps its own `default_modifier`, and can `disable` keybinds of the configs below
+/// it. A missing user config is fine as long as there is a system wide one.
+/// # Errors
+///
+/// This errors, when none of the configs is found or one of them is invalid
+pub fn load_layered(user_file: &Path, system_files: &[PathBuf]) -> Result<Config> {
+    let mut config: Option<Config> = None;
+    for file in system_files
+        .iter()
+        .rev()
+        .map(PathBuf::as_path)
+        .chain([user_file])
+    {
+        if !file.exists() {
+            continue;
+        }
+        // Errors in a system wide config are reported with its path.
+        let mut layer = include::load(file)
+            .and_then(Config::finish)
+            .map_err(|err| match err {
+                LeftError::InFile(..) => err,
+                err if file == user_file => err,
+                err => LeftError::InFile(file.to_owned(), Box::new(err)),
+            })?;
+        if let Some(mut inherited) = config {
+            inherited.override_vars(&layer.vars);
+            layer.inherited = Some(Box::new(inherited));
+        }
+        config = Some(layer);
+    }
+    config.ok_or(LeftError::NoConfigFound)
 }
 
 fn propagate_exit_chord(chords: Vec<&mut Keybind>, exit_chord: &Option<Keybind>) {
@@ -95,3 +407,10 @@ fn propagate_exit_chord(chords: Vec<&mut Keybind>, exit_chord: &Option<Keybind>)
         }
     }
 }
+
+/// Reads a set optional field without RON's `Some(...)`, so it works without `implicit_some`.
+fn some<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<T>, D::Error> {
+    T::deserialize(deserializer).map(Some)
+}
@@ -0,0 +1,118 @@
+use lefthk_core::xkeysym_lookup::{self, ModMask};
+
+use crate::{
+    config::{
+        command::Command,
+        key::Key,
+        keybind::{self, Keybind, Keybinds},
+    },
+    errors::{LeftError, Result},
+};
+
+/// Expands every keybind with a `sequence` like `"Super+a w t"` into nested chords. Sequences
+/// sharing a prefix end up in the same chord, as do sequences and chords written by hand for the
+/// same combo. The expanded keybinds come after the other ones of their chord.
+///
+/// Each step of a sequence is taken literally, so steps without a `+` don't get the default
+/// modifiers. Keybinds that also set a `modifier`, `key` or `combo` are left alone and reported
+/// when they are converted.
+/// # Errors
+///
+/// This errors, when a step of a sequence is bound to a command that isn't a chord.
+pub(crate) fn expand(keybinds: Keybinds, default_modifier: &[String]) -> Result<Keybinds> {
+    let (sequences, mut expanded): (Keybinds, Keybinds) =
+        keybinds.into_iter().partition(|kb| steps(kb).is_some());
+    for kb in &mut expanded {
+        if let Command::Chord(children) = &mut kb.command {
+            *children = expand(std::mem::take(children), default_modifier)?;
+        }
+    }
+    for mut kb in sequences {
+        if let Command::Chord(children) = kb.command {
+            kb.command = Command::Chord(expand(children, default_modifier)?);
+        }
+        if let Some(steps) = steps(&kb) {
+            insert(&mut expanded, &steps, kb, default_modifier)?;
+        }
+    }
+    Ok(expanded)
+}
+
+fn steps(kb: &Keybind) -> Option<Vec<String>> {
+    let sequence = kb.sequence.as_ref()?;
+    if kb.modifier.is_some() || kb.key != Key::default() || kb.combo.is_some() {
+        return None;
+    }
+    let steps: Vec<String> = sequence.split_whitespace().map(str::to_owned).collect();
+    (!steps.is_empty()).then_some(steps)
+}
+
+/// The modifier mask and key a single key is grabbed with, so the same combo is found however
+/// it's written.
+fn grabbed(
+    key: &str,
+    modifier: Option<&[String]>,
+    default_modifier: &[String],
+) -> Option<(ModMask, String)> {
+    let (modifier, key) = keybind::split_key(key, modifier, default_modifier).ok()?;
+    Some((xkeysym_lookup::into_modmask(&modifier), key))
+}
+
+fn insert(
+    keybinds: &mut Keybinds,
+    steps: &[String],
+    leaf: Keybind,
+    default_modifier: &[String],
+) -> Result<()> {
+    let Some((step, rest)) = steps.split_first() else {
+        return Ok(());
+    };
+    let modifier = (!step.contains('+')).then(Vec::new);
+    let key = Key::Key(step.clone());
+    if rest.is_empty() {
+        keybinds.push(Keybind {
+            modifier,
+            key,
+            sequence: None,
+            ..leaf
+        });
+        return Ok(());
+    }
+
+    let combo = grabbed(step, modifier.as_deref(), default_modifier);
+    let bound = keybinds.iter_mut().find(|kb| {
+        let key = match keybind::key(kb) {
+            Ok(Key::Key(key)) => key,
+            _ => return false,
+        };
+        combo.is_some() && grabbed(&key, kb.modifier.as_deref(), default_modifier) == combo
+    });
+    match bound {
+        Some(Keybind {
+            command: Command::Chord(children),
+            ..
+        }) => insert(children, rest, leaf, default_modifier)?,
+        Some(_) => return Err(LeftError::SequenceConflict(step.clone())),
+        None => {
+            let origin = leaf.origin.clone();
+            let mut children = Vec::new();
+            insert(&mut children, rest, leaf, default_modifier)?;
+            keybinds.push(Keybind {
+                command: Command::Chord(children),
+                modifier,
+                key,
+                combo: None,
+                sequence: None,
+                name: None,
+                description: None,
+                when: None,
+                output: None,
+                on_failure: None,
+                instance: None,
+                on_exit: None,
+                origin,
+            });
+        }
+    }
+    Ok(())
+}
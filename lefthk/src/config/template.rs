@@ -0,0 +1,201 @@
+use crate::{
+    config::{
+        command::Command,
+        key::Key,
+        keybind::{Keybind, Keybinds},
+    },
+    errors::{LeftError, Result},
+};
+
+/// Expands sxhkd style templates like `key: Key("Super+{1-9}")` into one keybind per value.
+///
+/// A template is a list of values in braces, each value being either literal or an ascending
+/// range of numbers or characters like `1-9` or `a-f`. `_` stands for an empty value. Several
+/// templates in one string expand to every combination of their values. Templates in an `Execute`
+/// are only expanded when the key is a template too, and have to expand to the same number of
+/// values. Braces can be escaped as `\{` and `\}`, variables like `${term}` are left alone.
+///
+/// Keybinds with invalid templates are kept as they are, [`check`] tells what is wrong with them.
+pub(crate) fn expand(keybinds: Keybinds) -> Keybinds {
+    let mut expanded = Vec::new();
+    for mut kb in keybinds {
+        if let Command::Chord(children) = kb.command {
+            kb.command = Command::Chord(expand(children));
+        }
+        match expansions(&kb) {
+            Ok(Some(expansions)) => expanded.extend(expansions.keybinds(&kb)),
+            Ok(None) | Err(_) => expanded.push(kb),
+        }
+    }
+    expanded
+}
+
+/// Makes sure the templates of a keybind can be expanded.
+pub(crate) fn check(kb: &Keybind) -> Result<()> {
+    expansions(kb).map(drop)
+}
+
+/// The values the templates of a keybind expand to.
+struct Expansions {
+    count: usize,
+    key: Option<Vec<String>>,
+    combo: Option<Vec<String>>,
+    sequence: Option<Vec<String>>,
+    cmd: Option<Vec<String>>,
+}
+
+impl Expansions {
+    fn keybinds(self, kb: &Keybind) -> Keybinds {
+        (0..self.count)
+            .map(|i| Keybind {
+                command: match (&kb.command, &self.cmd) {
+                    (Command::Execute(execute), Some(cmds)) => {
+                        Command::Execute(execute.with_cmd(cmds[i].clone()))
+                    }
+                    (command, _) => command.clone(),
+                },
+                key: self
+                    .key
+                    .as_ref()
+                    .map_or_else(|| kb.key.clone(), |keys| Key::Key(keys[i].clone())),
+                combo: self
+                    .combo
+                    .as_ref()
+                    .map_or_else(|| kb.combo.clone(), |combos| Some(combos[i].clone())),
+                sequence: self.sequence.as_ref().map_or_else(
+                    || kb.sequence.clone(),
+                    |sequences| Some(sequences[i].clone()),
+                ),
+                ..kb.clone()
+            })
+            .collect()
+    }
+}
+
+/// Returns `None` if the keybind has no templates.
+fn expansions(kb: &Keybind) -> Result<Option<Expansions>> {
+    let key = match &kb.key {
+        Key::Key(key) => expand_string(key)?,
+        Key::Keys(_) => None,
+    };
+    let combo = expand_optional(kb.combo.as_deref())?;
+    let sequence = expand_optional(kb.sequence.as_deref())?;
+
+    let values = [&key, &combo, &sequence];
+    let Some(count) = values.into_iter().flatten().map(Vec::len).max() else {
+        return Ok(None);
+    };
+    // Used to point out the keybind in errors.
+    let template = || match &kb.key {
+        Key::Key(key) if key.contains('{') => key.clone(),
+        _ => kb.combo.clone().or(kb.sequence.clone()).unwrap_or_default(),
+    };
+    if let Some(values) = values.into_iter().flatten().find(|v| v.len() != count) {
+        return Err(LeftError::TemplateLengthsDiffer(
+            template(),
+            count,
+            values.len(),
+        ));
+    }
+    let cmd = match &kb.command {
+        Command::Execute(execute) => expand_string(execute.cmd())?,
+        _ => None,
+    };
+    if let Some(values) = cmd.as_ref().filter(|v| v.len() != count) {
+        return Err(LeftError::TemplateLengthsDiffer(
+            template(),
+            count,
+            values.len(),
+        ));
+    }
+    Ok(Some(Expansions {
+        count,
+        key,
+        combo,
+        sequence,
+        cmd,
+    }))
+}
+
+fn expand_optional(template: Option<&str>) -> Result<Option<Vec<String>>> {
+    Ok(template.map(expand_string).transpose()?.flatten())
+}
+
+/// Expands all templates in a string, returns `None` if it doesn't contain any.
+pub(crate) fn expand_string(template: &str) -> Result<Option<Vec<String>>> {
+    let invalid = || LeftError::InvalidTemplate(template.to_owned());
+    let mut expanded = vec![String::new()];
+    let mut has_template = false;
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars.next().ok_or_else(invalid)?;
+                if !matches!(escaped, '{' | '}' | '\\') {
+                    expanded.iter_mut().for_each(|s| s.push('\\'));
+                }
+                expanded.iter_mut().for_each(|s| s.push(escaped));
+            }
+            // Variables like `${term}` are expanded later on.
+            '$' if chars.as_str().starts_with('{') => {
+                let end = chars.as_str().find('}').ok_or_else(invalid)?;
+                let variable = format!("${}", &chars.as_str()[..=end]);
+                expanded.iter_mut().for_each(|s| s.push_str(&variable));
+                chars = chars.as_str()[end + 1..].chars();
+            }
+            '{' => {
+                let mut group = String::new();
+                loop {
+                    match chars.next().ok_or_else(invalid)? {
+                        '}' => break,
+                        '{' => return Err(invalid()),
+                        c => group.push(c),
+                    }
+                }
+                let values = expand_group(&group);
+                expanded = expanded
+                    .iter()
+                    .flat_map(|prefix| values.iter().map(move |value| format!("{prefix}{value}")))
+                    .collect();
+                has_template = true;
+            }
+            '}' => return Err(invalid()),
+            c => expanded.iter_mut().for_each(|s| s.push(c)),
+        }
+    }
+    Ok(has_template.then_some(expanded))
+}
+
+fn expand_group(group: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    for value in group.split(',') {
+        let range = value
+            .split_once('-')
+            .and_then(|(start, end)| expand_range(start, end));
+        match range {
+            Some(range) => values.extend(range),
+            None if value == "_" => values.push(String::new()),
+            None => values.push(value.to_owned()),
+        }
+    }
+    values
+}
+
+fn expand_range(start: &str, end: &str) -> Option<Vec<String>> {
+    if let (Ok(start), Ok(end)) = (start.parse::<u32>(), end.parse::<u32>()) {
+        return (start <= end).then(|| (start..=end).map(|n| n.to_string()).collect());
+    }
+    let mut start_chars = start.chars();
+    let mut end_chars = end.chars();
+    match (
+        start_chars.next(),
+        start_chars.next(),
+        end_chars.next(),
+        end_chars.next(),
+    ) {
+        (Some(start), None, Some(end), None) if start <= end => {
+            Some((start..=end).map(String::from).collect())
+        }
+        _ => None,
+    }
+}
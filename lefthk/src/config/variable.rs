@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use crate::errors::{LeftError, Result};
+
+/// Expands `${name}` to the value of the variable from `vars`, `${env:NAME}` to the value of the
+/// environment variable and a `~` at the start of a word to the home directory. `$${` stands
+/// for a literal `${`.
+pub(crate) fn expand(value: &str, vars: &HashMap<String, String>) -> Result<String> {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find(['$', '~']) {
+        let (before, from) = rest.split_at(start);
+        expanded.push_str(before);
+        if let Some(after) = from.strip_prefix("$${") {
+            expanded.push_str("${");
+            rest = after;
+        } else if let Some(after) = from.strip_prefix("${") {
+            let end = after
+                .find('}')
+                .ok_or_else(|| LeftError::InvalidVariable(value.to_owned()))?;
+            expanded.push_str(&lookup(&after[..end], vars)?);
+            rest = &after[end + 1..];
+        } else if from.starts_with('~') && starts_word(&expanded) && ends_home(&from[1..]) {
+            match std::env::var("HOME") {
+                Ok(home) => expanded.push_str(&home),
+                Err(_) => expanded.push('~'),
+            }
+            rest = &from[1..];
+        } else {
+            // A `$` or `~` that isn't expanded, e.g. `$HOME` which is left to the shell.
+            expanded.push_str(&from[..1]);
+            rest = &from[1..];
+        }
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+fn lookup(name: &str, vars: &HashMap<String, String>) -> Result<String> {
+    if let Some(env) = name.strip_prefix("env:") {
+        return std::env::var(env).map_err(|_| LeftError::EnvVariableNotFound(env.to_owned()));
+    }
+    vars.get(name)
+        .cloned()
+        .ok_or_else(|| LeftError::VariableNotFound(name.to_owned()))
+}
+
+fn starts_word(before: &str) -> bool {
+    before.is_empty() || before.ends_with(char::is_whitespace) || before.ends_with(['=', ':'])
+}
+
+fn ends_home(after: &str) -> bool {
+    after.is_empty() || after.starts_with('/') || after.starts_with(char::is_whitespace)
+}
@@ -40,31 +40,73 @@ pub enum LeftError {
     RonError(#[from] ron::error::Error),
     #[error("RON spanned error {0}.")]
     SpannedError(#[from] ron::error::SpannedError),
+    #[error("TOML error: {0}.")]
+    TomlError(#[from] toml::de::Error),
+    #[error("YAML error: {0}.")]
+    YamlError(#[from] serde_yaml_ng::Error),
+    #[error("JSON error: {0}.")]
+    JsonError(#[from] serde_json::Error),
     #[error("XDG error: {0}.")]
     XdgBaseDirError(#[from] xdg::BaseDirectoriesError),
+    #[error(transparent)]
+    CoreError(#[from] lefthk_core::errors::LeftError),
 
+    #[error("Action {0:?} ends up referring to itself.")]
+    ActionCycle(String),
+    #[error("Action {0:?} is not defined.")]
+    ActionNotFound(String),
     #[error("No children found for chord.")]
     ChildrenNotFound,
     #[error("No command found for keybind.")]
     CommandNotFound,
     #[error("Creation of home config failed.")]
     ConfigPlaceFailed,
-    #[error("No key found for keybind.")]
-    KeyNotFound,
-    #[error("No modifier found for keybind.")]
-    ModifierNotFound,
+    #[error("Environment variable {0:?} is not set.")]
+    EnvVariableNotFound(String),
+    #[error("{}: {}", .0.display(), .1)]
+    InFile(std::path::PathBuf, Box<LeftError>),
+    #[error("Config {} ends up including itself.", .0.display())]
+    IncludeCycle(std::path::PathBuf),
+    #[error("Included config {} not found.", .0.display())]
+    IncludeNotFound(std::path::PathBuf),
+    #[error("Invalid combo {0:?}, expected something like \"Super+Shift+Return\".")]
+    InvalidCombo(String),
+    #[error("Invalid include pattern {0:?}.")]
+    InvalidInclude(String),
+    #[error("on_failure needs a single command like Execute or Spawn.")]
+    InvalidOnFailure,
+    #[error("Invalid template {0:?}, expected something like \"Super+{{1-9,0}}\".")]
+    InvalidTemplate(String),
+    #[error("Invalid variable in {0:?}, expected something like \"${{term}}\".")]
+    InvalidVariable(String),
+    #[error("Keybind needs exactly one of key, combo or sequence.")]
+    KeyOrComboNeeded,
+    #[error("Mode {0:?} is not defined.")]
+    ModeNotFound(String),
+    #[error("Modifiers are set both in the combo and the keybind.")]
+    ModifiersSetTwice,
     #[error("Command requires multiple keys.")]
     MultipleKeysNeeded,
     #[error("No config file found.")]
     NoConfigFound,
     #[error("The incorrect amount of keys is set for the number of values.")]
     NumberOfKeysDiffersFromValues,
+    #[error("No program set to spawn.")]
+    ProgramNotFound,
+    #[error("Sequence step {0:?} is already bound to a command that isn't a chord.")]
+    SequenceConflict(String),
     #[error("Command requires a single key.")]
     SingleKeyNeeded,
+    #[error("Templates of keybind {0:?} differ in length: {1} and {2} values.")]
+    TemplateLengthsDiffer(String, usize, usize),
+    #[error("{0} is not supported by lefthk.")]
+    Unsupported(String),
     #[error("No value set for execution.")]
     ValueNotFound,
     #[error("No values set for executions.")]
     ValuesNotFound,
+    #[error("Variable {0:?} is not defined.")]
+    VariableNotFound(String),
     #[error("X failed status error.")]
     XFailedStatus,
 }
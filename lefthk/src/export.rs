@@ -0,0 +1,117 @@
+use std::fmt::Write;
+
+use lefthk_core::config::{Keybind, command::utils::normalized_command::NormalizedCommand};
+use serde::{Deserialize, Serialize, de::IgnoredAny};
+
+/// The commands shown in a more readable way than their normalized form, leaving out the
+/// settings of their process.
+#[derive(Deserialize)]
+enum Readable {
+    Execute(String, #[serde(default)] IgnoredAny),
+    Spawn(Vec<String>, #[serde(default)] IgnoredAny),
+    Chord(Vec<Keybind>),
+}
+
+/// A line of the cheat sheet.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Row {
+    /// The combos to press one after another, more than one for keybinds in chords.
+    pub keys: Vec<String>,
+    pub name: Option<String>,
+    pub command: String,
+    pub description: Option<String>,
+}
+
+impl Row {
+    /// The chord path, e.g. `Super+c → h`.
+    #[must_use]
+    pub fn path(&self) -> String {
+        self.keys.join(" → ")
+    }
+}
+
+/// Lists every keybind, descending into chords.
+#[must_use]
+pub fn rows(keybinds: &[Keybind]) -> Vec<Row> {
+    let mut rows = Vec::new();
+    add_rows(keybinds, &[], &mut rows);
+    rows
+}
+
+fn add_rows(keybinds: &[Keybind], path: &[String], rows: &mut Vec<Row>) {
+    for kb in keybinds {
+        let mut keys = path.to_vec();
+        keys.push(kb.to_string());
+        let command = match ron::from_str::<Readable>(&kb.command.0) {
+            Ok(Readable::Chord(children)) => {
+                add_rows(&children, &keys, rows);
+                continue;
+            }
+            Ok(Readable::Execute(command, _)) => command,
+            Ok(Readable::Spawn(argv, _)) => argv.join(" "),
+            Err(_) => readable(&kb.command),
+        };
+        rows.push(Row {
+            keys,
+            name: kb.name.clone(),
+            command,
+            description: kb.description.clone(),
+        });
+    }
+}
+
+/// Commands like `Kill` are shown on a single line.
+fn readable(command: &NormalizedCommand) -> String {
+    command.0.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[must_use]
+pub fn markdown(rows: &[Row]) -> String {
+    let escape = |cell: &str| cell.replace('|', "\\|");
+    let mut md =
+        String::from("| Keys | Name | Command | Description |\n| --- | --- | --- | --- |\n");
+    for row in rows {
+        // Writing to a String can't fail.
+        let _ = writeln!(
+            md,
+            "| {} | {} | {} | {} |",
+            escape(&row.path()),
+            escape(row.name.as_deref().unwrap_or_default()),
+            escape(&row.command),
+            escape(row.description.as_deref().unwrap_or_default()),
+        );
+    }
+    md
+}
+
+#[must_use]
+pub fn html(rows: &[Row]) -> String {
+    let escape = |cell: &str| {
+        cell.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    };
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>lefthk keybinds</title>\n</head>\n<body>\n<table>\n<tr><th>Keys</th><th>Name</th><th>Command</th><th>Description</th></tr>\n",
+    );
+    for row in rows {
+        let _ = writeln!(
+            html,
+            "<tr><td><kbd>{}</kbd></td><td>{}</td><td><code>{}</code></td><td>{}</td></tr>",
+            escape(&row.path()),
+            escape(row.name.as_deref().unwrap_or_default()),
+            escape(&row.command),
+            escape(row.description.as_deref().unwrap_or_default()),
+        );
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+/// # Errors
+///
+/// This errors, when the rows can't be serialized
+pub fn json(rows: &[Row]) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(rows)
+}
@@ -0,0 +1,236 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::{
+    config::{Problem, keybind},
+    errors::{LeftError, Result},
+};
+
+use super::{Binding, Command, Imported, Trigger, logical_lines, problem};
+
+/// A `bindsym` line, converted once all modes are known.
+struct Bindsym {
+    line: usize,
+    combo: String,
+    command: String,
+}
+
+/// Converts the `bindsym` lines of an i3 or sway config into lefthk keybinds.
+///
+/// Variables set with `set $name value` are substituted. `exec` commands are converted as is,
+/// other i3 commands are kept commented out as `Execute("i3-msg ...")`. Switching to a mode
+/// becomes a chord of the bindings in its `mode "name" { }` block, and switching back to the
+/// default mode an exit chord. As chords end after the first key, a mode only stays active for a
+/// single binding. Key codes (`bindcode`), mouse bindings and `--release` are reported as problems.
+#[must_use]
+pub fn import(contents: &str, file: &Path) -> Imported {
+    let mut imported = Imported::default();
+    let mut variables: Vec<(String, String)> = Vec::new();
+    let mut modes: HashMap<String, Vec<Bindsym>> = HashMap::new();
+    let mut bindings = Vec::new();
+    let mut mode: Option<String> = None;
+    for (line, text) in logical_lines(contents) {
+        if let Some(definition) = text.trim().strip_prefix("set ") {
+            if let Some((name, value)) = definition.trim().split_once(char::is_whitespace) {
+                let value = substitute(value.trim(), &variables);
+                variables.retain(|(defined, _)| defined != name);
+                variables.push((name.to_owned(), value));
+                // Longer names first, so `$mod` doesn't replace the start of `$mod2`.
+                variables.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+            }
+            continue;
+        }
+        let text = substitute(text.trim(), &variables);
+        let mut words = text.split_whitespace();
+        match words.next() {
+            Some("mode") if text.ends_with('{') => {
+                let name = unquote(
+                    words
+                        .filter(|word| !word.starts_with("--") && *word != "{")
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                        .as_str(),
+                )
+                .to_owned();
+                modes.entry(name.clone()).or_default();
+                mode = Some(name);
+            }
+            Some("}") => mode = None,
+            Some("bindcode") => imported.problems.push(problem(
+                file,
+                line,
+                LeftError::Unsupported(format!("The key code binding {text:?}")),
+            )),
+            Some("bindsym") => {
+                let mut combo = None;
+                let mut unsupported = None;
+                for word in words.by_ref() {
+                    match word {
+                        "--release" => unsupported = Some("Running on release"),
+                        "--border" | "--whole-window" | "--exclude-titlebar" => {
+                            unsupported = Some("The mouse binding");
+                        }
+                        flag if flag.starts_with("--") => {}
+                        word => {
+                            combo = Some(word.to_owned());
+                            break;
+                        }
+                    }
+                }
+                let command = words.collect::<Vec<_>>().join(" ");
+                let bindsym = match (combo, unsupported) {
+                    (Some(combo), None) if !command.is_empty() => Bindsym {
+                        line,
+                        combo,
+                        command,
+                    },
+                    (_, Some(what)) => {
+                        imported.problems.push(problem(
+                            file,
+                            line,
+                            LeftError::Unsupported(format!("{what} in {text:?}")),
+                        ));
+                        continue;
+                    }
+                    _ => {
+                        imported
+                            .problems
+                            .push(problem(file, line, LeftError::CommandNotFound));
+                        continue;
+                    }
+                };
+                match &mode {
+                    Some(mode) => modes.entry(mode.clone()).or_default().push(bindsym),
+                    None => bindings.push(bindsym),
+                }
+            }
+            _ => {}
+        }
+    }
+    let converter = Converter {
+        file,
+        modes: &modes,
+    };
+    imported.bindings = converter.convert_all(&bindings, &mut Vec::new(), &mut imported.problems);
+    imported
+}
+
+struct Converter<'a> {
+    file: &'a Path,
+    modes: &'a HashMap<String, Vec<Bindsym>>,
+}
+
+impl Converter<'_> {
+    /// Converts the bindings of a mode, `stack` holding the modes it was entered from.
+    fn convert_all(
+        &self,
+        bindings: &[Bindsym],
+        stack: &mut Vec<String>,
+        problems: &mut Vec<Problem>,
+    ) -> Vec<Binding> {
+        let mut converted = Vec::new();
+        for bindsym in bindings {
+            match self.convert(bindsym, stack, problems) {
+                Ok(binding) => converted.push(binding),
+                Err(err) => problems.push(problem(self.file, bindsym.line, err)),
+            }
+        }
+        converted
+    }
+
+    fn convert(
+        &self,
+        bindsym: &Bindsym,
+        stack: &mut Vec<String>,
+        problems: &mut Vec<Problem>,
+    ) -> Result<Binding> {
+        let trigger = Trigger::Key(convert_combo(&bindsym.combo)?);
+        // Leaving a mode after running a command happens anyway, as chords end after one key.
+        let command = bindsym
+            .command
+            .strip_suffix("mode \"default\"")
+            .or_else(|| bindsym.command.strip_suffix("mode default"))
+            .map_or(bindsym.command.as_str(), |command| {
+                command.trim_end().trim_end_matches([';', ',']).trim_end()
+            });
+
+        if command.is_empty() {
+            return Ok(Binding::new(trigger, Command::ExitChord));
+        }
+        if let Some(exec) = command.strip_prefix("exec ") {
+            let exec = exec.trim_start();
+            let exec = exec.strip_prefix("--no-startup-id").unwrap_or(exec);
+            return Ok(Binding::new(
+                trigger,
+                Command::Execute(unquote(exec.trim()).to_owned()),
+            ));
+        }
+        if let Some(name) = command.strip_prefix("mode ") {
+            let name = unquote(name.trim());
+            let bindings = self
+                .modes
+                .get(name)
+                .ok_or_else(|| LeftError::ModeNotFound(name.to_owned()))?;
+            if stack.iter().any(|entered| entered == name) {
+                return Err(LeftError::Unsupported(format!(
+                    "Switching back to mode {name:?}"
+                )));
+            }
+            stack.push(name.to_owned());
+            let children = self.convert_all(bindings, stack, problems);
+            stack.pop();
+            // A mode without a usable binding would be an empty chord, which isn't valid.
+            let commented_out = children.iter().all(|child| child.commented_out);
+            return Ok(Binding {
+                trigger,
+                command: Command::Chord(children),
+                commented_out,
+            });
+        }
+        Ok(Binding {
+            trigger,
+            command: Command::Execute(format!("i3-msg {command}")),
+            commented_out: true,
+        })
+    }
+}
+
+/// Converts a combo like `Mod4+Shift+q` into one lefthk understands.
+fn convert_combo(combo: &str) -> Result<String> {
+    let mut names: Vec<&str> = combo.split('+').collect();
+    let key = names.pop().unwrap_or_default();
+    if key.starts_with("button") {
+        return Err(LeftError::Unsupported(format!(
+            "The mouse button in {combo:?}"
+        )));
+    }
+    let modifier = names
+        .into_iter()
+        .map(|name| match name {
+            "Ctrl" | "Control" => Ok("Control".to_owned()),
+            "Logo" => Ok("Super".to_owned()),
+            "Shift" | "Alt" | "Super" | "Mod1" | "Mod3" | "Mod4" | "Mod5" => Ok(name.to_owned()),
+            _ => Err(LeftError::Unsupported(format!("The modifier {name:?}"))),
+        })
+        .collect::<Result<Vec<String>>>()?;
+    keybind::check_names(key, &modifier)?;
+    Ok(modifier
+        .into_iter()
+        .chain([key.to_owned()])
+        .collect::<Vec<_>>()
+        .join("+"))
+}
+
+fn substitute(text: &str, variables: &[(String, String)]) -> String {
+    variables
+        .iter()
+        .fold(text.to_owned(), |text, (name, value)| {
+            text.replace(name, value)
+        })
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+}
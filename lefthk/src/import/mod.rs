@@ -0,0 +1,131 @@
+pub mod i3;
+pub mod sxhkd;
+
+use std::{fmt::Write, path::Path};
+
+use crate::{config::Problem, errors::LeftError};
+
+/// What a converted keybind is triggered by.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Trigger {
+    /// A single combo like `Super+Return`.
+    Key(String),
+    /// Combos pressed one after another like `Super+a w`.
+    Sequence(String),
+}
+
+/// What a converted keybind does.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    Execute(String),
+    Chord(Vec<Binding>),
+    ExitChord,
+}
+
+/// A keybind converted from the config of another hotkey daemon.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Binding {
+    pub trigger: Trigger,
+    pub command: Command,
+    /// Kept in the output for reference, but commented out as it can't work as is.
+    pub commented_out: bool,
+}
+
+impl Binding {
+    #[must_use]
+    pub fn new(trigger: Trigger, command: Command) -> Self {
+        Self {
+            trigger,
+            command,
+            commented_out: false,
+        }
+    }
+
+    /// Writes the keybind at the given indentation level. Commented out keybinds, and everything
+    /// in them, get the `//` at their own indentation.
+    fn write_ron(&self, ron: &mut String, indent: usize, comment_at: Option<usize>) {
+        let comment_at = comment_at.or(self.commented_out.then_some(indent));
+        let inner = indent + 1;
+        write_line(ron, indent, comment_at, "Keybind(");
+        match &self.command {
+            Command::Execute(value) => {
+                // Shell syntax like `${HOME}` would be read as a lefthk variable otherwise.
+                let value = value.replace("${", "$${");
+                let command = format!("command: Execute({}),", quote(&value));
+                write_line(ron, inner, comment_at, &command);
+            }
+            Command::Chord(children) => {
+                write_line(ron, inner, comment_at, "command: Chord([");
+                for child in children {
+                    child.write_ron(ron, inner + 1, comment_at);
+                }
+                write_line(ron, inner, comment_at, "]),");
+            }
+            Command::ExitChord => write_line(ron, inner, comment_at, "command: ExitChord,"),
+        }
+        let trigger = match &self.trigger {
+            Trigger::Key(combo) => format!("key: Key({}),", quote(combo)),
+            Trigger::Sequence(sequence) => format!("sequence: {},", quote(sequence)),
+        };
+        write_line(ron, inner, comment_at, &trigger);
+        write_line(ron, indent, comment_at, "),");
+    }
+}
+
+fn write_line(ron: &mut String, indent: usize, comment_at: Option<usize>, line: &str) {
+    let indentation = match comment_at {
+        Some(at) => format!("{}// {}", "    ".repeat(at), "    ".repeat(indent - at)),
+        None => "    ".repeat(indent),
+    };
+    // Writing to a String can't fail.
+    let _ = writeln!(ron, "{indentation}{line}");
+}
+
+/// The result of converting a config, keeping everything that couldn't be converted as problems.
+#[derive(Debug, Default)]
+pub struct Imported {
+    pub bindings: Vec<Binding>,
+    pub problems: Vec<Problem>,
+}
+
+impl Imported {
+    /// Writes the converted keybinds as a lefthk config in RON.
+    #[must_use]
+    pub fn to_ron(&self) -> String {
+        let mut ron = String::from("#![enable(implicit_some)]\nConfig(\n    keybinds: [\n");
+        for binding in &self.bindings {
+            binding.write_ron(&mut ron, 2, None);
+        }
+        ron.push_str("    ],\n)\n");
+        ron
+    }
+}
+
+fn quote(value: &str) -> String {
+    ron::to_string(value).unwrap_or_else(|_| format!("{value:?}"))
+}
+
+fn problem(file: &Path, line: usize, error: LeftError) -> Problem {
+    Problem {
+        location: format!("{}:{}", file.display(), line),
+        error,
+    }
+}
+
+/// Joins lines ending in `\` with the next one, keeping the number of the first line.
+fn logical_lines(contents: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    let mut continued = false;
+    for (i, line) in contents.lines().enumerate() {
+        let (line, continues) = match line.strip_suffix('\\') {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+        match lines.last_mut() {
+            Some((_, last)) if continued => last.push_str(line.trim_start()),
+            _ => lines.push((i + 1, line.to_owned())),
+        }
+        continued = continues;
+    }
+    lines
+}
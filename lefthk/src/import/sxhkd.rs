@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use crate::{
+    config::{keybind, template},
+    errors::{LeftError, Result},
+};
+
+use super::{Binding, Command, Imported, Trigger, logical_lines, problem};
+
+/// Converts an sxhkdrc into lefthk keybinds.
+///
+/// Hotkeys start at the beginning of a line and are followed by their indented command, both can
+/// be continued on the next line with a trailing `\`. Brace templates are expanded like sxhkd
+/// does, and `;` chains become sequences. Release (`@`), replay (`~`), locked chains (`:`) and
+/// mouse buttons have no lefthk equivalent and are reported as problems.
+#[must_use]
+pub fn import(contents: &str, file: &Path) -> Imported {
+    let mut imported = Imported::default();
+    let mut hotkey: Option<(usize, String)> = None;
+    for (line, text) in logical_lines(contents) {
+        let trimmed = text.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !text.starts_with(char::is_whitespace) {
+            if let Some((line, _)) = hotkey.replace((line, trimmed.to_owned())) {
+                imported
+                    .problems
+                    .push(problem(file, line, LeftError::CommandNotFound));
+            }
+            continue;
+        }
+        let converted = match hotkey.take() {
+            Some((line, keys)) => convert(&keys, trimmed).map_err(|err| (line, err)),
+            None => Err((
+                line,
+                LeftError::Unsupported(format!("A command without hotkey ({trimmed:?})")),
+            )),
+        };
+        match converted {
+            Ok(bindings) => imported.bindings.extend(bindings),
+            Err((line, err)) => imported.problems.push(problem(file, line, err)),
+        }
+    }
+    if let Some((line, _)) = hotkey {
+        imported
+            .problems
+            .push(problem(file, line, LeftError::CommandNotFound));
+    }
+    imported
+}
+
+fn convert(hotkey: &str, command: &str) -> Result<Vec<Binding>> {
+    // Like sxhkd, braces in the command are only a template when the hotkey is one.
+    let Some(hotkeys) = template::expand_string(hotkey)? else {
+        return convert_hotkey(hotkey, command.to_owned()).map(|binding| vec![binding]);
+    };
+    let commands = match template::expand_string(command)? {
+        Some(commands) if commands.len() != hotkeys.len() => {
+            return Err(LeftError::TemplateLengthsDiffer(
+                hotkey.to_owned(),
+                hotkeys.len(),
+                commands.len(),
+            ));
+        }
+        Some(commands) => commands,
+        None => vec![command.to_owned(); hotkeys.len()],
+    };
+    hotkeys
+        .iter()
+        .zip(commands)
+        .map(|(hotkey, command)| convert_hotkey(hotkey, command))
+        .collect()
+}
+
+fn convert_hotkey(hotkey: &str, command: String) -> Result<Binding> {
+    if hotkey.contains(':') {
+        return Err(LeftError::Unsupported(format!(
+            "The locked chain in {hotkey:?}"
+        )));
+    }
+    let combos = hotkey
+        .split(';')
+        .map(convert_chord)
+        .collect::<Result<Vec<String>>>()?;
+    let trigger = match combos.as_slice() {
+        [combo] => Trigger::Key(combo.clone()),
+        _ => Trigger::Sequence(combos.join(" ")),
+    };
+    Ok(Binding::new(trigger, Command::Execute(command)))
+}
+
+/// Converts a single chord like `super + shift + Return` into a combo like `Super+Shift+Return`.
+fn convert_chord(chord: &str) -> Result<String> {
+    if chord.contains('@') {
+        return Err(LeftError::Unsupported(format!(
+            "Running on release in {chord:?}"
+        )));
+    }
+    if chord.contains('~') {
+        return Err(LeftError::Unsupported(format!(
+            "Replaying the event in {chord:?}"
+        )));
+    }
+    let mut names: Vec<&str> = chord.split('+').map(str::trim).collect();
+    let key = names.pop().unwrap_or_default();
+    if key.starts_with("button") {
+        return Err(LeftError::Unsupported(format!(
+            "The mouse button in {chord:?}"
+        )));
+    }
+    let modifier = names
+        .into_iter()
+        .map(|name| match name.to_lowercase().as_str() {
+            "super" => Ok("Super".to_owned()),
+            "alt" | "meta" => Ok("Alt".to_owned()),
+            "ctrl" | "control" => Ok("Control".to_owned()),
+            "shift" => Ok("Shift".to_owned()),
+            "mode_switch" => Ok("Mod5".to_owned()),
+            lower @ ("mod1" | "mod3" | "mod4" | "mod5") => Ok(lower.replace("mod", "Mod")),
+            _ => Err(LeftError::Unsupported(format!("The modifier {name:?}"))),
+        })
+        .collect::<Result<Vec<String>>>()?;
+    keybind::check_names(key, &modifier)?;
+    Ok(modifier
+        .into_iter()
+        .chain([key.to_owned()])
+        .collect::<Vec<_>>()
+        .join("+"))
+}
@@ -1,5 +1,5 @@
 use crate::errors::LeftError;
-use clap::{Arg, Command as ClapCommand};
+use clap::{Arg, ArgAction, Command as ClapCommand};
 use lefthk_core::{
     config::{Command, Config, command},
     ipc::Pipe,
@@ -8,7 +8,9 @@ use lefthk_core::{
 use std::{
     fs,
     io::Write,
-    sync::atomic::{AtomicBool, Ordering},
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
 };
 use xdg::BaseDirectories;
 
@@ -16,58 +18,201 @@ use tracing_subscriber::{filter::EnvFilter, filter::LevelFilter, fmt, layer::Sub
 
 pub mod config;
 pub mod errors;
+pub mod export;
+pub mod import;
 mod tests;
 
 const QUIT_COMMAND: &str = "quit";
 const RELOAD_COMMAND: &str = "reload";
+const WATCH_FLAG: &str = "watch";
+const CONFIG_OPTION: &str = "config";
+const CHECK_COMMAND: &str = "check";
+const TRIGGER_COMMAND: &str = "trigger";
+const IMPORT_COMMAND: &str = "import";
+const SXHKD_FORMAT: &str = "sxhkd";
+const I3_FORMAT: &str = "i3";
+const EXPORT_COMMAND: &str = "export";
+const FORMAT_OPTION: &str = "format";
 
 fn main() {
-    setup_logging();
     let app = get_app();
     let matches = app.get_matches();
-    tracing::info!("lefthk booted!");
+    // Imports and exports are written to stdout, so they run without logging set up.
+    if let Some(matches) = matches.subcommand_matches(IMPORT_COMMAND) {
+        import_config(matches);
+        return;
+    }
+    // So are exports and the result of a check, to keep them usable in scripts.
+    let export = matches.subcommand_matches(EXPORT_COMMAND);
+    if export.is_none() && matches.subcommand_matches(CHECK_COMMAND).is_none() {
+        setup_logging();
+        tracing::info!("lefthk booted!");
+    }
+
+    let custom_config_file = matches
+        .get_one::<PathBuf>(CONFIG_OPTION)
+        .map(|file| errors::exit_on_error!(std::path::absolute(file)));
+
+    // System wide configs are only layered below the default config.
+    let mut system_files = if custom_config_file.is_none() {
+        config::system_config_files()
+    } else {
+        Vec::new()
+    };
 
-    if matches.contains_id(QUIT_COMMAND) {
+    if let Some(export) = export {
+        let config_file = custom_config_file
+            .unwrap_or_else(|| errors::exit_on_error!(config::default_config_file()));
+        let format = export
+            .get_one::<String>(FORMAT_OPTION)
+            .map_or("md", String::as_str);
+        export_config(&config_file, &system_files, format);
+    } else if matches.subcommand_matches(CHECK_COMMAND).is_some() {
+        let config_file = custom_config_file
+            .unwrap_or_else(|| errors::exit_on_error!(config::default_config_file()));
+        check_config(&config_file, &system_files);
+    } else if let Some(combo) = matches.get_one::<String>(TRIGGER_COMMAND) {
+        send_command(&command::Trigger::new(combo));
+    } else if matches.get_flag(QUIT_COMMAND) {
         send_command(&command::Kill::new());
-    } else if matches.contains_id(RELOAD_COMMAND) {
-        send_command(&command::Reload::new());
+    } else if matches.get_flag(RELOAD_COMMAND) {
+        match custom_config_file {
+            Some(config_file) => send_command(&command::LoadConfig::new(config_file)),
+            None => send_command(&command::Reload::new()),
+        }
     } else {
         let mut old_config = None;
         let path = BaseDirectories::with_prefix(lefthk_core::LEFTHK_DIR_NAME);
+        let mut config_file = custom_config_file
+            .unwrap_or_else(|| errors::exit_on_error!(config::default_config_file()));
+        let watch = matches.get_flag(WATCH_FLAG);
+        // The runtime outlives reloads, so the output of running children is still forwarded.
+        let rt = errors::return_on_error!(tokio::runtime::Runtime::new());
+        let _rt_guard = rt.enter();
+        // Handed from worker to worker, so instance policies still know the ones started before.
+        let mut children = None;
         loop {
-            let config = match config::load() {
+            let config = match config::load_layered(&config_file, &system_files) {
                 Ok(config) => config,
                 Err(err) => {
+                    tracing::error!("Unable to load new config due to error: {}", err);
                     if let Some(config) = old_config {
+                        tracing::info!("Keeping the previous config");
                         config
                     } else {
-                        tracing::error!("Unable to load new config due to error: {}", err);
                         return;
                     }
                 }
             };
-            let kill_requested = AtomicBool::new(false);
-            let completed = std::panic::catch_unwind(|| {
-                let rt = errors::return_on_error!(tokio::runtime::Runtime::new());
-                let _rt_guard = rt.enter();
-
-                let status =
-                    rt.block_on(Worker::new(config.mapped_bindings(), path.clone()).event_loop());
-                kill_requested.store(status == Status::Kill, Ordering::SeqCst);
-            });
+            let status = Mutex::new(Status::Continue);
+            let completed = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                let mut worker = Worker::new(config.mapped_bindings(), path.clone());
+                if let Some(children) = children.take() {
+                    worker.children.merge(children);
+                }
+                if watch {
+                    // The user config is watched even if it's missing, to pick it up once created.
+                    let mut files = config.files();
+                    if !files.contains(&config_file) {
+                        files.push(config_file.clone());
+                    }
+                    worker.watch_config(&files);
+                }
+                let new_status = rt.block_on(worker.event_loop());
+                children = Some(worker.children);
+                *status.lock().unwrap_or_else(PoisonError::into_inner) = new_status;
+            }));
 
             match completed {
                 Ok(()) => tracing::info!("Completed"),
                 Err(err) => tracing::error!("Completed with error: {:?}", err),
             }
-            if kill_requested.load(Ordering::SeqCst) {
-                return;
+            match status.into_inner().unwrap_or_else(PoisonError::into_inner) {
+                Status::Kill => return,
+                Status::LoadConfig(new_config_file) => {
+                    config_file = new_config_file;
+                    system_files.clear();
+                }
+                Status::Reload | Status::Continue => {}
             }
             old_config = Some(config);
         }
     }
 }
 
+fn check_config(config_file: &Path, system_files: &[PathBuf]) {
+    let problems = match config::load_layered(config_file, system_files) {
+        Ok(config) => config.check(),
+        Err(err) => {
+            // Errors in included files carry their own path.
+            let (file, err) = match err {
+                LeftError::InFile(file, err) => (file, *err),
+                err => (config_file.to_owned(), err),
+            };
+            match err {
+                LeftError::SpannedError(err) => eprintln!(
+                    "{}:{}:{}: {}",
+                    file.display(),
+                    err.span.start.line,
+                    err.span.start.col,
+                    err.code
+                ),
+                err => eprintln!("{}: {}", file.display(), err),
+            }
+            std::process::exit(1);
+        }
+    };
+    if problems.is_empty() {
+        println!("{}: OK", config_file.display());
+        return;
+    }
+    for problem in &problems {
+        eprintln!("{problem}");
+    }
+    std::process::exit(1);
+}
+
+fn export_config(config_file: &Path, system_files: &[PathBuf], format: &str) {
+    let config = match config::load_layered(config_file, system_files) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}: {}", config_file.display(), err);
+            std::process::exit(1);
+        }
+    };
+    let rows = export::rows(&config.mapped_bindings());
+    match format {
+        "html" => print!("{}", export::html(&rows)),
+        "json" => println!("{}", errors::exit_on_error!(export::json(&rows))),
+        _ => print!("{}", export::markdown(&rows)),
+    }
+}
+
+fn import_config(matches: &clap::ArgMatches) {
+    let Some((format, matches)) = matches.subcommand() else {
+        return;
+    };
+    let Some(file) = matches.get_one::<PathBuf>("FILE") else {
+        return;
+    };
+    let contents = match fs::read_to_string(file) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("{}: {}", file.display(), err);
+            std::process::exit(1);
+        }
+    };
+    let imported = match format {
+        SXHKD_FORMAT => import::sxhkd::import(&contents, file),
+        I3_FORMAT => import::i3::import(&contents, file),
+        _ => return,
+    };
+    for problem in &imported.problems {
+        eprintln!("{problem}");
+    }
+    print!("{}", imported.to_ron());
+}
+
 fn send_command(command: &impl Command) {
     let path = BaseDirectories::with_prefix(lefthk_core::LEFTHK_DIR_NAME);
     let pipe_name = Pipe::pipe_name();
@@ -82,14 +227,77 @@ fn get_app() -> ClapCommand {
             Arg::new(QUIT_COMMAND)
                 .short('q')
                 .long(QUIT_COMMAND)
+                .action(ArgAction::SetTrue)
                 .help("Quit a running daemon instance"),
         )
         .arg(
             Arg::new(RELOAD_COMMAND)
                 .short('r')
                 .long(RELOAD_COMMAND)
+                .action(ArgAction::SetTrue)
                 .help("Reload daemon to apply changes to config"),
         )
+        .arg(
+            Arg::new(TRIGGER_COMMAND)
+                .short('t')
+                .long(TRIGGER_COMMAND)
+                .value_name("COMBO")
+                .help(
+                    "Run the keybind for a combo like \"Super+Shift+Return\" or a keybind name in a running daemon",
+                ),
+        )
+        .arg(
+            Arg::new(WATCH_FLAG)
+                .short('w')
+                .long(WATCH_FLAG)
+                .action(ArgAction::SetTrue)
+                .help("Reload automatically when the config files change"),
+        )
+        .arg(
+            Arg::new(CONFIG_OPTION)
+                .short('c')
+                .long(CONFIG_OPTION)
+                .value_name("PATH")
+                .value_parser(clap::value_parser!(PathBuf))
+                .global(true)
+                .help("Use the given config file instead of the default one"),
+        )
+        .subcommand(
+            ClapCommand::new(CHECK_COMMAND)
+                .about("Check the config file for errors and report all of them"),
+        )
+        .subcommand(
+            ClapCommand::new(EXPORT_COMMAND)
+                .about("Print every keybind, including the ones in chords, as a cheat sheet")
+                .arg(
+                    Arg::new(FORMAT_OPTION)
+                        .short('f')
+                        .long(FORMAT_OPTION)
+                        .value_parser(["md", "html", "json"])
+                        .default_value("md"),
+                ),
+        )
+        .subcommand(
+            ClapCommand::new(IMPORT_COMMAND)
+                .about("Convert the config of another hotkey daemon and print it as lefthk config")
+                .subcommand_required(true)
+                .subcommand(
+                    ClapCommand::new(SXHKD_FORMAT)
+                        .about("Convert an sxhkdrc")
+                        .arg(import_file_arg()),
+                )
+                .subcommand(
+                    ClapCommand::new(I3_FORMAT)
+                        .about("Convert the bindsym lines of an i3 or sway config")
+                        .arg(import_file_arg()),
+                ),
+        )
+}
+
+fn import_file_arg() -> Arg {
+    Arg::new("FILE")
+        .required(true)
+        .value_parser(clap::value_parser!(PathBuf))
 }
 
 fn setup_logging() {
@@ -1,8 +1,15 @@
 /// Config Testing
 #[cfg(test)]
 mod config {
-    use lefthk_core::config::Config;
     use lefthk_core::config::command::utils::normalized_command::NormalizedCommand;
+    use lefthk_core::config::command::utils::{
+        output::Output,
+        process::{Instance, OnExit, Process},
+    };
+    use lefthk_core::config::{
+        Command, Config,
+        command::{Execute, Spawn},
+    };
 
     use crate::config::Config as Cfg;
 
@@ -64,6 +71,1014 @@ Config(
         assert_eq!(conf_mapped.len(), 0);
     }
 
+    #[test]
+    fn load_config_from_path() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let config_file = dir.path().join("custom.ron");
+        std::fs::write(
+            &config_file,
+            r#"Config(
+    default_modifier: ["Mod4"],
+    keybinds: []
+)"#,
+        )
+        .unwrap();
+
+        let conf = crate::config::load(&config_file).unwrap();
+        assert_eq!(conf.default_modifier, vec!["Mod4".to_string()]);
+        assert!(crate::config::load(&dir.path().join("missing.ron")).is_err());
+    }
+
+    #[test]
+    fn check_reports_all_problems() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Execute("st -e htop"),
+            key: Key("Retrun"),
+        ),
+        Keybind(
+            command: Executes(["st -e htop", "st -e btm"]),
+            key: Keys(["x"]),
+        ),
+        Keybind(
+            command: Chord([
+                Keybind(
+                    command: Execute("st -e htop"),
+                    modifier: ["Ctrl"],
+                    key: Key("c"),
+                ),
+            ]),
+            key: Key("c"),
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let problems: Vec<String> = conf.check().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec![
+                r#"keybinds[0]: Unknown key "Retrun". Did you mean "Return"?"#.to_string(),
+                "keybinds[1]: The incorrect amount of keys is set for the number of values."
+                    .to_string(),
+                r#"keybinds[2] > Chord[0]: Unknown modifier "Ctrl". Did you mean "Control"?"#
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn drop_unknown_names() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Execute("st -e htop"),
+            key: Key("Retrun"),
+        ),
+        Keybind(
+            command: Execute("st -e htop"),
+            modifier: ["Ctrl"],
+            key: Key("x"),
+        ),
+        Keybind(
+            command: Execute("st -e htop"),
+            key: Key("Return"),
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let conf_mapped = conf.mapped_bindings();
+
+        // Neither typo may turn into a keybind, especially not one without modifiers
+        assert_eq!(conf_mapped.len(), 1);
+        assert_eq!(conf_mapped[0].key, "Return");
+    }
+
+    #[test]
+    fn parse_combo_config() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Execute("st -e htop"),
+            key: Key("Super+Shift+Return"),
+        ),
+        Keybind(
+            command: Execute("st -e btm"),
+            combo: "Alt+x",
+        ),
+        Keybind(
+            command: Executes(["st -e htop", "st -e btm"]),
+            key: Keys(["Shift+1", "2"]),
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let combos: Vec<String> = conf
+            .mapped_bindings()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            combos,
+            vec!["Super+Shift+Return", "Alt+x", "Shift+1", "Mod4+2"]
+        );
+    }
+
+    #[test]
+    fn check_combo_problems() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Execute("st -e htop"),
+            modifier: ["Shift"],
+            key: Key("Super+Return"),
+        ),
+        Keybind(
+            command: Execute("st -e htop"),
+            key: Key("x"),
+            combo: "Super+x",
+        ),
+        Keybind(
+            command: Execute("st -e htop"),
+        ),
+        Keybind(
+            command: Execute("st -e htop"),
+            combo: "Super++",
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let problems: Vec<String> = conf.check().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec![
+                "keybinds[0]: Modifiers are set both in the combo and the keybind.".to_string(),
+                "keybinds[1]: Keybind needs exactly one of key, combo or sequence.".to_string(),
+                "keybinds[2]: Keybind needs exactly one of key, combo or sequence.".to_string(),
+                r#"keybinds[3]: Invalid combo "Super++", expected something like "Super+Shift+Return"."#
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_sequences() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Execute("st -e htop"),
+            sequence: "Super+a w t",
+        ),
+        Keybind(
+            command: Execute("st -e btm"),
+            sequence: "Super+a w Shift+s",
+        ),
+        Keybind(
+            command: Execute("st -e bpytop"),
+            sequence: "Super+a x",
+        ),
+        Keybind(
+            command: Execute("st"),
+            sequence: "Super+Return",
+        ),
+    ]
+)"#;
+        let expected = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Chord([
+                Keybind(
+                    command: Chord([
+                        Keybind(
+                            command: Execute("st -e htop"),
+                            modifier: [],
+                            key: Key("t"),
+                        ),
+                        Keybind(
+                            command: Execute("st -e btm"),
+                            key: Key("Shift+s"),
+                        ),
+                    ]),
+                    modifier: [],
+                    key: Key("w"),
+                ),
+                Keybind(
+                    command: Execute("st -e bpytop"),
+                    modifier: [],
+                    key: Key("x"),
+                ),
+            ]),
+            key: Key("Super+a"),
+        ),
+        Keybind(
+            command: Execute("st"),
+            key: Key("Super+Return"),
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        assert_eq!(conf, Cfg::try_from(expected.to_string()).unwrap());
+        assert!(conf.check().is_empty());
+        assert_eq!(conf.mapped_bindings().len(), 2);
+    }
+
+    #[test]
+    fn merge_sequences_with_chords() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Execute("st -e htop"),
+            sequence: "Super+a h",
+        ),
+        Keybind(
+            command: Chord([
+                Keybind(
+                    command: Execute("st"),
+                    key: Key("t"),
+                ),
+            ]),
+            key: Key("a"),
+        ),
+        Keybind(
+            command: Execute("st -e btm"),
+            modifier: ["Shift"],
+            sequence: "Super+a b",
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let mapped = conf.mapped_bindings();
+        assert_eq!(mapped.len(), 1);
+        assert!(mapped[0].command.0.contains("st -e htop"));
+        assert!(mapped[0].command.0.contains("Execute(\\\"st\\\")"));
+        let problems: Vec<String> = conf.check().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec!["keybinds[1]: Modifiers are set both in the combo and the keybind.".to_string()]
+        );
+
+        let conflict = r#"#![enable(implicit_some)]
+Config(keybinds: [
+    Keybind(command: Execute("st"), key: Key("Super+a")),
+    Keybind(command: Execute("st -e htop"), sequence: "Super+a h"),
+])"#;
+        let err = Cfg::try_from(conflict.to_string()).unwrap_err();
+        assert!(
+            matches!(err, crate::errors::LeftError::SequenceConflict(step) if step == "Super+a")
+        );
+    }
+
+    #[test]
+    fn expand_templates() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Execute("leftwm-command 'SendWorkspaceToTag 0 {0-2}'"),
+            key: Key("{1-3}"),
+        ),
+        Keybind(
+            command: Execute("xdotool key {a,b}; awk '\\{print $1\\}'"),
+            combo: "Super+{_,Shift+}x",
+        ),
+        Keybind(
+            command: Execute("awk '{print $1}'"),
+            key: Key("y"),
+        ),
+        Keybind(
+            command: Reload,
+            sequence: "Super+r {a-b}",
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let conf_mapped = conf.mapped_bindings();
+        let combos: Vec<String> = conf_mapped.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            combos,
+            vec![
+                "Mod4+1",
+                "Mod4+2",
+                "Mod4+3",
+                "Super+x",
+                "Super+Shift+x",
+                "Mod4+y",
+                "Super+r"
+            ]
+        );
+        let commands: Vec<String> = conf_mapped[..6]
+            .iter()
+            .map(|kb| kb.command.to_string())
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                r#"Execute("leftwm-command \'SendWorkspaceToTag 0 0\'")"#,
+                r#"Execute("leftwm-command \'SendWorkspaceToTag 0 1\'")"#,
+                r#"Execute("leftwm-command \'SendWorkspaceToTag 0 2\'")"#,
+                r#"Execute("xdotool key a; awk \'{print $1}\'")"#,
+                r#"Execute("xdotool key b; awk \'{print $1}\'")"#,
+                r#"Execute("awk \'{print $1}\'")"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn template_lengths_differ() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Execute("leftwm-command 'SendWorkspaceToTag 0 {0-7}'"),
+            key: Key("{1-9}"),
+        ),
+        Keybind(
+            command: Execute("st"),
+            key: Key("{a,b"),
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let problems: Vec<String> = conf.check().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec![
+                r#"keybinds[0]: Templates of keybind "{1-9}" differ in length: 9 and 8 values."#
+                    .to_string(),
+                r#"keybinds[1]: Invalid template "{a,b", expected something like "Super+{1-9,0}"."#
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_valid_config() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4", "Shift"],
+    keybinds: [
+        Keybind(
+            command: Execute("st -e htop"),
+            modifier: ["None"],
+            key: Key("Return"),
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        assert!(conf.check().is_empty());
+    }
+
+    #[test]
+    fn load_includes() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let config_file = dir.path().join("config.ron");
+        std::fs::create_dir(dir.path().join("work")).unwrap();
+        std::fs::write(
+            &config_file,
+            r#"Config(
+    default_modifier: ["Mod4"],
+    include: ["media.ron", "work/*.ron"],
+    keybinds: [
+        Keybind(
+            command: Execute("st"),
+            key: Key("Return"),
+        ),
+    ]
+)"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("media.ron"),
+            r#"Config(
+    keybinds: [
+        Keybind(
+            command: Execute("pamixer -i 5"),
+            key: Key("XF86AudioRaiseVolum"),
+        ),
+    ]
+)"#,
+        )
+        .unwrap();
+        for (name, modifier) in [("b", r#"["Mod1"]"#), ("a", "[]")] {
+            std::fs::write(
+                dir.path().join("work").join(format!("{name}.ron")),
+                format!(
+                    r#"Config(
+    default_modifier: {modifier},
+    keybinds: [Keybind(command: Execute("{name}"), key: Key("{name}"))],
+)"#
+                ),
+            )
+            .unwrap();
+        }
+
+        let conf = crate::config::load(&config_file).unwrap();
+        let work = dir.path().join("work");
+        assert_eq!(
+            conf.files(),
+            vec![
+                config_file.clone(),
+                dir.path().join("media.ron"),
+                work.join("a.ron"),
+                work.join("b.ron"),
+            ]
+        );
+        let keys: Vec<String> = conf
+            .mapped_bindings()
+            .into_iter()
+            .map(|kb| kb.key)
+            .collect();
+        assert_eq!(keys, vec!["Return", "a", "b"]);
+        // Included files keep their own default modifier.
+        let modifiers: Vec<Vec<String>> = conf
+            .mapped_bindings()
+            .into_iter()
+            .map(|kb| kb.modifier)
+            .collect();
+        assert_eq!(modifiers, vec![vec!["Mod4"], vec!["Mod4"], vec!["Mod1"]]);
+
+        let problems: Vec<String> = conf.check().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec![format!(
+                r#"{}: keybinds[0]: Unknown key "XF86AudioRaiseVolum". Did you mean "XF86AudioRaiseVolume"?"#,
+                dir.path().join("media.ron").display()
+            )]
+        );
+    }
+
+    #[test]
+    fn detect_include_cycles() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let config_file = dir.path().join("config.ron");
+        std::fs::write(
+            &config_file,
+            r#"Config(include: ["other.ron"], keybinds: [])"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("other.ron"),
+            r#"Config(include: ["./config.ron"], keybinds: [])"#,
+        )
+        .unwrap();
+
+        let err = crate::config::load(&config_file).unwrap_err();
+        assert!(matches!(err, crate::errors::LeftError::IncludeCycle(_)));
+    }
+
+    #[test]
+    fn resolve_actions() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    actions: {
+        "term": Execute("alacritty"),
+        "default_term": Action("term"),
+    },
+    keybinds: [
+        Keybind(
+            command: Action("default_term"),
+            key: Key("Return"),
+        ),
+        Keybind(
+            command: Chord([
+                Keybind(
+                    command: Action("term"),
+                    key: Key("t"),
+                ),
+            ]),
+            key: Key("c"),
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let mapped = conf.mapped_bindings();
+        assert_eq!(mapped[0].command.0, "Execute(\"alacritty\")");
+        assert!(mapped[1].command.0.contains("Execute(\\\"alacritty\\\")"));
+
+        let undefined = r#"Config(keybinds: [Keybind(command: Action("browser"), key: Key("b"))])"#;
+        let err = Cfg::try_from(undefined.to_string()).unwrap_err();
+        assert_eq!(err.to_string(), r#"Action "browser" is not defined."#);
+
+        let cycle = r#"Config(
+    actions: {"a": Action("b"), "b": Action("a")},
+    keybinds: [Keybind(command: Action("a"), key: Key("a"))],
+)"#;
+        let err = Cfg::try_from(cycle.to_string()).unwrap_err();
+        assert!(matches!(err, crate::errors::LeftError::ActionCycle(_)));
+
+        let chord_cycle = r#"Config(
+    actions: {"a": Chord([Keybind(command: Action("a"), key: Key("x"))])},
+    keybinds: [Keybind(command: Action("a"), key: Key("a"))],
+)"#;
+        let err = Cfg::try_from(chord_cycle.to_string()).unwrap_err();
+        assert!(matches!(err, crate::errors::LeftError::ActionCycle(_)));
+    }
+
+    #[test]
+    fn expand_variables() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    vars: {"term": "alacritty"},
+    keybinds: [
+        Keybind(
+            command: Execute("${term} --working-directory ~/src"),
+            key: Key("Return"),
+        ),
+        Keybind(
+            command: Executes(["${env:HOME}/bin/x", "echo $${term} ~user $HOME"]),
+            key: Keys(["x", "y"]),
+        ),
+        Keybind(
+            command: Execute("${browser}"),
+            key: Key("b"),
+        ),
+        Keybind(
+            command: Execute("${term} -e {htop,btm}"),
+            key: Key("{h,j}"),
+        ),
+    ]
+)"#;
+        let home = std::env::var("HOME").unwrap();
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let commands: Vec<String> = conf
+            .mapped_bindings()
+            .into_iter()
+            .map(|kb| kb.command.0)
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                format!("Execute(\"alacritty --working-directory {home}/src\")"),
+                format!("Execute(\"{home}/bin/x\")"),
+                "Execute(\"echo ${term} ~user $HOME\")".to_string(),
+                "Execute(\"alacritty -e htop\")".to_string(),
+                "Execute(\"alacritty -e btm\")".to_string(),
+            ]
+        );
+
+        let problems: Vec<String> = conf.check().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec![r#"keybinds[2]: Variable "browser" is not defined."#.to_string()]
+        );
+    }
+
+    #[test]
+    fn override_variables_of_system_configs() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let system_file = dir.path().join("system.ron");
+        let user_file = dir.path().join("user.ron");
+        std::fs::write(
+            &system_file,
+            r#"Config(
+    vars: {"term": "xterm"},
+    keybinds: [Keybind(command: Execute("${term}"), key: Key("Super+Return"))],
+)"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &user_file,
+            r#"Config(vars: {"term": "alacritty"}, keybinds: [])"#,
+        )
+        .unwrap();
+
+        let conf = crate::config::load_layered(&user_file, &[system_file]).unwrap();
+        let mapped = conf.mapped_bindings();
+        assert_eq!(mapped[0].command.0, "Execute(\"alacritty\")");
+    }
+
+    #[test]
+    fn ignore_unmatched_configs_entirely() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let system_file = dir.path().join("system.ron");
+        let user_file = dir.path().join("user.ron");
+        let included_file = dir.path().join("included.ron");
+        let actions_file = dir.path().join("actions.ron");
+        std::fs::write(
+            &system_file,
+            r#"Config(
+    include: ["included.ron"],
+    vars: {"term": "xterm"},
+    keybinds: [
+        Keybind(command: Execute("${term}"), key: Key("Super+Return")),
+        Keybind(command: Execute("${browser}"), key: Key("Super+b")),
+    ],
+)"#,
+        )
+        .unwrap();
+        let unmatched = r#"#![enable(implicit_some)]
+Config(
+    when: Hostname("no-such-host"),
+    vars: {"term": "alacritty", "browser": "firefox"},
+    actions: {"run": Execute("dmenu_run")},
+    keybinds: [],
+)"#;
+        std::fs::write(&user_file, unmatched).unwrap();
+        std::fs::write(&included_file, unmatched).unwrap();
+        std::fs::write(
+            &actions_file,
+            r#"Config(
+    include: ["included.ron"],
+    keybinds: [Keybind(command: Action("run"), key: Key("Super+p"))],
+)"#,
+        )
+        .unwrap();
+
+        // Neither the user config nor the included file hand down their variables.
+        let conf = crate::config::load_layered(&user_file, &[system_file]).unwrap();
+        let mapped = conf.mapped_bindings();
+        assert_eq!(mapped.len(), 1);
+        assert_eq!(mapped[0].command.0, "Execute(\"xterm\")");
+        let problems: Vec<String> = conf.check().iter().map(ToString::to_string).collect();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].ends_with(r#"Variable "browser" is not defined."#));
+
+        // Nor their actions.
+        let err = crate::config::load(&actions_file).unwrap_err();
+        assert!(matches!(err, crate::errors::LeftError::ActionNotFound(name) if name == "run"));
+    }
+
+    #[test]
+    fn spawn_programs() {
+        let config = r#"Config(
+    default_modifier: ["Mod4"],
+    shell: ["bash", "-lc"],
+    vars: {"term": "alacritty"},
+    keybinds: [
+        Keybind(command: Spawn(["${term}", "-e", "htop; btm"]), key: Key("Return")),
+        Keybind(command: Execute("${term} -e htop"), key: Key("h")),
+        Keybind(command: Spawn([]), key: Key("s")),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let commands: Vec<NormalizedCommand> = conf
+            .mapped_bindings()
+            .into_iter()
+            .map(|kb| kb.command)
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                Spawn::new(&["alacritty", "-e", "htop; btm"]).normalize(),
+                Spawn::new(&["bash", "-lc", "alacritty -e htop"]).normalize(),
+            ]
+        );
+
+        let problems: Vec<String> = conf.check().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec!["keybinds[2]: No program set to spawn.".to_string()]
+        );
+    }
+
+    #[test]
+    fn execute_with_process_settings() {
+        let config = r#"Config(
+    default_modifier: ["Mod4"],
+    output: Log,
+    on_exit: Terminate,
+    vars: {"level": "debug"},
+    actions: {"notify": Execute("notify-send failed")},
+    keybinds: [
+        Keybind(
+            command: Execute(cmd: "make {build,test}", cwd: "~/proj", env: {"RUST_LOG": "${level}"}),
+            key: Key("{b,t}"),
+            output: Some(File("make.log")),
+            on_failure: Some(Action("notify")),
+            instance: Some(Single),
+            on_exit: Some(Kill(500)),
+        ),
+        Keybind(command: Execute(cmd: "env", clear_env: true), key: Key("e")),
+        Keybind(command: Spawn(cmd: ["htop"], cwd: "/tmp"), key: Key("h")),
+        Keybind(command: Executes(cmd: ["date", "id"], env: {"TZ": "UTC"}), key: Keys(["d", "i"])),
+    ]
+)"#;
+        let home = std::env::var("HOME").unwrap();
+        let output = Output::File("make.log".to_string());
+        let on_failure = Execute::new(&"notify-send failed").with_process(Process {
+            output: output.clone(),
+            on_exit: OnExit::Kill(500),
+            ..Process::default()
+        });
+        let process = Process {
+            env: [("RUST_LOG".to_string(), "debug".to_string())].into(),
+            clear_env: false,
+            cwd: Some(format!("{home}/proj")),
+            output,
+            on_failure: Some(on_failure.normalize()),
+            instance: Instance::Single,
+            on_exit: OnExit::Kill(500),
+        };
+        let base = Process {
+            output: Output::Log,
+            on_exit: OnExit::Terminate,
+            ..Process::default()
+        };
+        let utc = Process {
+            env: [("TZ".to_string(), "UTC".to_string())].into(),
+            ..base.clone()
+        };
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let commands: Vec<NormalizedCommand> = conf
+            .mapped_bindings()
+            .into_iter()
+            .map(|kb| kb.command)
+            .collect();
+        assert_eq!(
+            commands,
+            vec![
+                Execute::new(&"make build")
+                    .with_process(process.clone())
+                    .normalize(),
+                Execute::new(&"make test").with_process(process).normalize(),
+                Execute::new(&"env")
+                    .with_process(Process {
+                        clear_env: true,
+                        ..base.clone()
+                    })
+                    .normalize(),
+                Spawn::new(&["htop"])
+                    .with_process(Process {
+                        cwd: Some("/tmp".to_string()),
+                        ..base.clone()
+                    })
+                    .normalize(),
+                Execute::new(&"date").with_process(utc.clone()).normalize(),
+                Execute::new(&"id").with_process(utc).normalize(),
+            ]
+        );
+
+        let typo =
+            r#"Config(keybinds: [Keybind(command: Execute(cmd: "make", evn: {}), key: Key("m"))])"#;
+        let err = Cfg::try_from(typo.to_string()).unwrap_err();
+        assert!(err.to_string().contains("`evn`"), "{err}");
+    }
+
+    #[test]
+    fn evaluate_conditions() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let config_file = dir.path().join("config.ron");
+        let home = std::env::var("HOME").unwrap();
+        let hostname = nix::unistd::gethostname().unwrap();
+        std::fs::write(
+            &config_file,
+            format!(
+                r#"#![enable(implicit_some)]
+Config(
+    include: [
+        "elsewhere.ron",
+        "here.ron",
+        Include(path: "laptop.ron", when: Hostname("no such host")),
+    ],
+    keybinds: [
+        Keybind(
+            command: Execute("home"),
+            key: Key("Super+a"),
+            when: Env("HOME", "{home}"),
+        ),
+        Keybind(
+            command: Execute("other host"),
+            key: Key("Super+b"),
+            when: All([Hostname("{}"), Not(Hostname("{}"))]),
+        ),
+        Keybind(
+            command: Chord([
+                Keybind(
+                    command: Execute("nested display"),
+                    key: Key("d"),
+                    when: Display("not a display"),
+                ),
+                Keybind(
+                    command: Execute("nested"),
+                    key: Key("n"),
+                ),
+            ]),
+            key: Key("Super+c"),
+        ),
+        Keybind(
+            command: Chord([
+                Keybind(
+                    command: Execute("nested elsewhere"),
+                    key: Key("e"),
+                    when: Hostname("no such host"),
+                ),
+            ]),
+            key: Key("Super+d"),
+        ),
+    ]
+)"#,
+                hostname.to_string_lossy(),
+                hostname.to_string_lossy()
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("elsewhere.ron"),
+            r#"#![enable(implicit_some)]
+Config(
+    when: Any([Hostname("no such host"), Env("LEFTHK_NO_SUCH_VAR", "1")]),
+    include: ["here.ron"],
+    keybinds: [Keybind(command: Execute("elsewhere"), key: Key("Super+e"))],
+)"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("here.ron"),
+            // Like in includes, a set `when` doesn't need `Some(...)` or `implicit_some`.
+            r#"Config(
+    when: Not(Display("not a display")),
+    keybinds: [Keybind(command: Execute("here"), key: Key("Super+h"))],
+)"#,
+        )
+        .unwrap();
+
+        std::fs::write(
+            dir.path().join("laptop.ron"),
+            r#"Config(keybinds: [Keybind(command: Execute("laptop"), key: Key("Super+l"))])"#,
+        )
+        .unwrap();
+
+        let conf = crate::config::load(&config_file).unwrap();
+        let keys: Vec<String> = conf
+            .mapped_bindings()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(keys, vec!["Super+a", "Super+c", "Super+h"]);
+        assert!(conf.check().is_empty());
+        assert!(
+            !conf.mapped_bindings()[1]
+                .command
+                .0
+                .contains("nested display")
+        );
+    }
+
+    #[test]
+    fn keep_names_and_descriptions() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(
+            command: Execute("st"),
+            key: Key("Return"),
+            name: "terminal",
+            description: "Open terminal",
+        ),
+        Keybind(
+            command: Execute("st -e htop"),
+            sequence: "Super+a h",
+            name: "htop",
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let mapped = conf.mapped_bindings();
+        assert_eq!(mapped[0].name.as_deref(), Some("terminal"));
+        assert_eq!(mapped[0].description.as_deref(), Some("Open terminal"));
+        // The chord generated for the sequence has no name, the keybind in it keeps its own.
+        assert_eq!(mapped[1].name, None);
+        assert!(mapped[1].command.0.contains(r#"name: Some("htop")"#));
+    }
+
+    #[test]
+    fn load_other_formats() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let configs = [
+            (
+                "config.ron",
+                r#"Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(command: Chord([Keybind(command: Execute("st"), key: Key("t"))]), key: Key("c")),
+        Keybind(command: ExitChord, key: Key("Escape")),
+    ]
+)"#,
+            ),
+            (
+                "config.toml",
+                r#"default_modifier = ["Mod4"]
+
+[[keybinds]]
+key = { Key = "c" }
+command = { Chord = [{ command = { Execute = "st" }, key = { Key = "t" } }] }
+
+[[keybinds]]
+key = { Key = "Escape" }
+command = "ExitChord"
+"#,
+            ),
+            (
+                "config.yaml",
+                r"default_modifier: [Mod4]
+keybinds:
+  - key: !Key c
+    command: !Chord
+      - key: !Key t
+        command: !Execute st
+  - key: !Key Escape
+    command: ExitChord
+",
+            ),
+            (
+                "config.json",
+                r#"{
+    "default_modifier": ["Mod4"],
+    "keybinds": [
+        {"key": {"Key": "c"}, "command": {"Chord": [{"key": {"Key": "t"}, "command": {"Execute": "st"}}]}},
+        {"key": {"Key": "Escape"}, "command": "ExitChord"}
+    ]
+}"#,
+            ),
+        ];
+        let mut mapped = Vec::new();
+        for (name, contents) in configs {
+            let config_file = dir.path().join(name);
+            std::fs::write(&config_file, contents).unwrap();
+            let conf = crate::config::load(&config_file).unwrap();
+            assert!(conf.check().is_empty(), "{name}");
+            mapped.push(conf.mapped_bindings());
+        }
+        // The exit chord is handed down to the chord in every format.
+        assert!(mapped[0][0].command.0.contains("ExitChord"));
+        assert!(mapped.iter().all(|bindings| *bindings == mapped[0]));
+
+        let config_file = dir.path().join("broken.json");
+        std::fs::write(&config_file, "{").unwrap();
+        let err = crate::config::load(&config_file).unwrap_err();
+        assert!(matches!(err, crate::errors::LeftError::JsonError(_)));
+    }
+
+    #[test]
+    fn layer_user_config_on_system_configs() {
+        let dir = tempfile::tempdir_in("../target").unwrap();
+        let system_file = dir.path().join("system.ron");
+        let user_file = dir.path().join("user.ron");
+        std::fs::write(
+            &system_file,
+            r#"Config(
+    default_modifier: ["Mod4"],
+    keybinds: [
+        Keybind(command: Execute("st"), key: Key("Return")),
+        Keybind(command: Execute("dmenu_run"), key: Key("p")),
+    ]
+)"#,
+        )
+        .unwrap();
+        let system_files = vec![system_file];
+
+        // Without a user config the system config is used as is.
+        let conf = crate::config::load_layered(&user_file, &system_files).unwrap();
+        assert_eq!(conf.mapped_bindings().len(), 2);
+
+        std::fs::write(
+            &user_file,
+            r#"Config(
+    disable: ["Mod4+p", "Mod4+Nope"],
+    keybinds: [
+        Keybind(command: Execute("rofi -show run"), key: Key("Super+d")),
+    ]
+)"#,
+        )
+        .unwrap();
+        let conf = crate::config::load_layered(&user_file, &system_files).unwrap();
+        let keys: Vec<String> = conf
+            .mapped_bindings()
+            .into_iter()
+            .map(|kb| kb.key)
+            .collect();
+        assert_eq!(keys, vec!["d", "Return"]);
+
+        let problems: Vec<String> = conf.check().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec![r#"disable[1]: Unknown key "Nope". Did you mean "n"?"#.to_string()]
+        );
+
+        let missing = dir.path().join("missing.ron");
+        let err = crate::config::load_layered(&missing, &[]).unwrap_err();
+        assert!(matches!(err, crate::errors::LeftError::NoConfigFound));
+    }
+
     #[test]
     fn parse_none_config() {
         // Define empty string
@@ -151,3 +1166,297 @@ Config(
         );
     }
 }
+
+/// Import Testing
+#[cfg(test)]
+mod import {
+    use std::path::Path;
+
+    use lefthk_core::config::Config;
+
+    use crate::config::Config as Cfg;
+    use crate::import::{Binding, Command, Trigger, i3, sxhkd};
+
+    #[test]
+    fn import_sxhkd() {
+        let sxhkdrc = r"# terminal
+super + Return
+	st
+
+super + {_,shift + }{h,l}
+	bspc node -{f,s} {west,east}
+
+super + a ; {w,shift + s}
+	echo {one,two} \
+	  more
+
+@super + x
+	echo release
+
+ctrl + Retrun
+	oops
+super + z
+";
+        let imported = sxhkd::import(sxhkdrc, Path::new("sxhkdrc"));
+
+        let problems: Vec<String> = imported.problems.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec![
+                r#"sxhkdrc:12: Running on release in "@super + x" is not supported by lefthk."#,
+                r#"sxhkdrc:15: Unknown key "Retrun". Did you mean "Return"?"#,
+                "sxhkdrc:17: No command found for keybind.",
+            ]
+        );
+        assert_eq!(
+            imported.bindings[..2],
+            [
+                Binding::new(
+                    Trigger::Key("Super+Return".to_string()),
+                    Command::Execute("st".to_string()),
+                ),
+                Binding::new(
+                    Trigger::Key("Super+h".to_string()),
+                    Command::Execute("bspc node -f west".to_string()),
+                ),
+            ]
+        );
+        assert_eq!(
+            imported.bindings.last(),
+            Some(&Binding::new(
+                Trigger::Sequence("Super+a Shift+s".to_string()),
+                Command::Execute("echo two more".to_string()),
+            ))
+        );
+
+        // The output is a valid config, the sequence sharing its first chord.
+        let conf = Cfg::try_from(imported.to_ron()).unwrap();
+        assert!(conf.check().is_empty());
+        assert_eq!(conf.mapped_bindings().len(), 6);
+    }
+
+    #[test]
+    fn keep_braces_of_plain_hotkeys() {
+        let sxhkdrc = "super + p\n\tps aux | awk '{print $1}'\n";
+        let imported = sxhkd::import(sxhkdrc, Path::new("sxhkdrc"));
+
+        assert!(imported.problems.is_empty());
+        assert_eq!(
+            imported.bindings,
+            vec![Binding::new(
+                Trigger::Key("Super+p".to_string()),
+                Command::Execute("ps aux | awk '{print $1}'".to_string()),
+            )]
+        );
+        let conf = Cfg::try_from(imported.to_ron()).unwrap();
+        assert_eq!(
+            conf.mapped_bindings()[0].command.0,
+            r#"Execute("ps aux | awk \'{print $1}\'")"#
+        );
+    }
+
+    #[test]
+    fn escape_shell_variables() {
+        let sxhkdrc = "super + n\n\tnotify-send \"${HOME}\"\n";
+        let i3_config = "bindsym Mod4+n exec notify-send \"${HOME}\"\n";
+        for imported in [
+            sxhkd::import(sxhkdrc, Path::new("sxhkdrc")),
+            i3::import(i3_config, Path::new("config")),
+        ] {
+            assert!(imported.problems.is_empty());
+            assert!(
+                imported
+                    .to_ron()
+                    .contains(r#"Execute("notify-send \"$${HOME}\"")"#)
+            );
+            let conf = Cfg::try_from(imported.to_ron()).unwrap();
+            assert!(conf.check().is_empty());
+            assert_eq!(
+                conf.mapped_bindings()[0].command.0,
+                r#"Execute("notify-send \"${HOME}\"")"#
+            );
+        }
+    }
+
+    #[test]
+    fn import_i3() {
+        let config = r#"set $mod Mod4
+set $term st -e
+bindsym $mod+Return exec $term htop
+bindsym $mod+Shift+q kill
+bindcode 172 exec playerctl play-pause
+bindsym $mod+r mode "resize"
+bindsym $mod+g mode "gaps"
+
+mode "resize" {
+    bindsym h resize shrink width 10 px
+    bindsym Return exec --no-startup-id notify-send done; mode "default"
+    bindsym Escape mode "default"
+}
+"#;
+        let imported = i3::import(config, Path::new("config"));
+
+        let problems: Vec<String> = imported.problems.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            problems,
+            vec![
+                r#"config:5: The key code binding "bindcode 172 exec playerctl play-pause" is not supported by lefthk."#,
+                r#"config:7: Mode "gaps" is not defined."#,
+            ]
+        );
+        assert_eq!(
+            imported.bindings,
+            vec![
+                Binding::new(
+                    Trigger::Key("Mod4+Return".to_string()),
+                    Command::Execute("st -e htop".to_string()),
+                ),
+                Binding {
+                    trigger: Trigger::Key("Mod4+Shift+q".to_string()),
+                    command: Command::Execute("i3-msg kill".to_string()),
+                    commented_out: true,
+                },
+                Binding::new(
+                    Trigger::Key("Mod4+r".to_string()),
+                    Command::Chord(vec![
+                        Binding {
+                            trigger: Trigger::Key("h".to_string()),
+                            command: Command::Execute(
+                                "i3-msg resize shrink width 10 px".to_string()
+                            ),
+                            commented_out: true,
+                        },
+                        Binding::new(
+                            Trigger::Key("Return".to_string()),
+                            Command::Execute("notify-send done".to_string()),
+                        ),
+                        Binding::new(Trigger::Key("Escape".to_string()), Command::ExitChord),
+                    ]),
+                ),
+            ]
+        );
+
+        // Commented out keybinds are left out of the config.
+        let conf = Cfg::try_from(imported.to_ron()).unwrap();
+        assert!(conf.check().is_empty());
+        assert_eq!(conf.mapped_bindings().len(), 2);
+    }
+
+    #[test]
+    fn import_nested_i3_modes() {
+        let config = r#"bindsym Mod4+r mode "resize"
+bindsym Mod4+e mode "empty"
+mode "resize" {
+    bindsym g mode "gaps"
+}
+mode "gaps" {
+    bindsym Escape mode "default"
+}
+mode "empty" {
+    bindsym h resize shrink width 10 px
+}
+"#;
+        let imported = i3::import(config, Path::new("config"));
+
+        // A mode without usable bindings is commented out instead of becoming an empty chord.
+        let expected = r#"#![enable(implicit_some)]
+Config(
+    keybinds: [
+        Keybind(
+            command: Chord([
+                Keybind(
+                    command: Chord([
+                        Keybind(
+                            command: ExitChord,
+                            key: Key("Escape"),
+                        ),
+                    ]),
+                    key: Key("g"),
+                ),
+            ]),
+            key: Key("Mod4+r"),
+        ),
+        // Keybind(
+        //     command: Chord([
+        //         Keybind(
+        //             command: Execute("i3-msg resize shrink width 10 px"),
+        //             key: Key("h"),
+        //         ),
+        //     ]),
+        //     key: Key("Mod4+e"),
+        // ),
+    ],
+)
+"#;
+        assert_eq!(imported.to_ron(), expected);
+        let conf = Cfg::try_from(imported.to_ron()).unwrap();
+        assert!(conf.check().is_empty());
+    }
+}
+
+/// Export Testing
+#[cfg(test)]
+mod export {
+    use lefthk_core::config::Config;
+
+    use crate::config::Config as Cfg;
+    use crate::export;
+
+    #[test]
+    fn export_cheat_sheet() {
+        let config = r#"#![enable(implicit_some)]
+Config(
+    default_modifier: ["Super"],
+    keybinds: [
+        Keybind(
+            command: Execute(cmd: "ps aux | less", env: {"PAGER": "less"}),
+            key: Key("p"),
+            name: "processes",
+            description: "List processes",
+        ),
+        Keybind(
+            command: Chord([
+                Keybind(
+                    command: Execute("st -e htop"),
+                    modifier: [],
+                    key: Key("h"),
+                ),
+            ]),
+            key: Key("c"),
+        ),
+        Keybind(
+            command: ExitChord,
+            modifier: [],
+            key: Key("Escape"),
+        ),
+    ]
+)"#;
+        let conf = Cfg::try_from(config.to_string()).unwrap();
+        let rows = export::rows(&conf.mapped_bindings());
+        let paths: Vec<(String, &str)> = rows
+            .iter()
+            .map(|row| (row.path(), row.command.as_str()))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("Super+p".to_string(), "ps aux | less"),
+                ("Super+c → h".to_string(), "st -e htop"),
+                ("Super+c → Escape".to_string(), "ExitChord"),
+                ("Escape".to_string(), "ExitChord"),
+            ]
+        );
+
+        let markdown = export::markdown(&rows);
+        assert!(markdown.contains("| Super+p | processes | ps aux \\| less | List processes |\n"));
+        let html = export::html(&rows);
+        assert!(
+            html.contains(
+                "<td><kbd>Super+c → h</kbd></td><td></td><td><code>st -e htop</code></td>"
+            )
+        );
+        let json: Vec<serde_json::Value> =
+            serde_json::from_str(&export::json(&rows).unwrap()).unwrap();
+        assert_eq!(json[1]["keys"], serde_json::json!(["Super+c", "h"]));
+    }
+}